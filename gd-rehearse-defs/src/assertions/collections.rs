@@ -0,0 +1,194 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Helpers backing the [`assert_dict_contains!`](crate::assert_dict_contains),
+//! [`assert_array_eq_unordered!`](crate::assert_array_eq_unordered) and
+//! [`assert_array_contains!`](crate::assert_array_contains) macros.
+//!
+//! Entries are compared as [`Variant`]s, which uses the engine's equality semantics - nested arrays and dictionaries are
+//! compared by their contents.
+
+use godot::builtin::{Array, Dictionary, Variant};
+use godot::meta::{ArrayElement, FromGodot, ToGodot};
+
+fn to_variants<T: ArrayElement + FromGodot + ToGodot>(array: &Array<T>) -> Vec<Variant> {
+    array
+        .iter_shared()
+        .map(|value| value.to_variant())
+        .collect()
+}
+
+fn list(values: &[Variant]) -> String {
+    values
+        .iter()
+        .map(Variant::to_string)
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Removes values from `pool` matching `expected`, returning those without a match.
+fn take_matching(pool: &mut Vec<Variant>, expected: Vec<Variant>) -> Vec<Variant> {
+    let mut missing = Vec::new();
+    for value in expected {
+        match pool.iter().position(|candidate| *candidate == value) {
+            Some(idx) => {
+                pool.swap_remove(idx);
+            }
+            None => missing.push(value),
+        }
+    }
+    missing
+}
+
+/// Checks that `actual` contains every key of `expected` with an equal value.
+///
+/// Returns failure message listing missing keys and mismatched values, or `None` if the check passed.
+pub fn dict_contains(actual: &Dictionary, expected: &Dictionary) -> Option<String> {
+    let mut problems = Vec::new();
+    for (key, value) in expected.iter_shared() {
+        match actual.get(key.clone()) {
+            None => problems.push(format!("missing key {key} (expected value: {value})")),
+            Some(actual_value) if actual_value != value => {
+                problems.push(format!("key {key}: expected {value}, got {actual_value}"))
+            }
+            Some(_) => {}
+        }
+    }
+
+    (!problems.is_empty()).then(|| {
+        format!(
+            "dictionary doesn't contain expected entries:\n  - {}\n  actual: {actual}",
+            problems.join("\n  - ")
+        )
+    })
+}
+
+/// Checks that `actual` and `expected` contain the same elements, regardless of their order.
+///
+/// Returns failure message listing missing and extra elements, or `None` if the check passed.
+pub fn array_eq_unordered<T, U>(actual: &Array<T>, expected: &Array<U>) -> Option<String>
+where
+    T: ArrayElement + FromGodot + ToGodot,
+    U: ArrayElement + FromGodot + ToGodot,
+{
+    let mut extra = to_variants(actual);
+    let missing = take_matching(&mut extra, to_variants(expected));
+
+    if missing.is_empty() && extra.is_empty() {
+        return None;
+    }
+    let mut message = String::from("arrays don't contain the same elements:");
+    if !missing.is_empty() {
+        message.push_str(&format!("\n  missing: [{}]", list(&missing)));
+    }
+    if !extra.is_empty() {
+        message.push_str(&format!("\n  extra: [{}]", list(&extra)));
+    }
+    message.push_str(&format!("\n  actual: {actual:?}"));
+    Some(message)
+}
+
+/// Checks that `actual` contains all elements of `expected`. Duplicated elements need to be present as many times.
+///
+/// Returns failure message listing missing elements, or `None` if the check passed.
+pub fn array_contains<T, U>(actual: &Array<T>, expected: &Array<U>) -> Option<String>
+where
+    T: ArrayElement + FromGodot + ToGodot,
+    U: ArrayElement + FromGodot + ToGodot,
+{
+    let missing = take_matching(&mut to_variants(actual), to_variants(expected));
+
+    (!missing.is_empty()).then(|| {
+        format!(
+            "array doesn't contain expected elements:\n  missing: [{}]\n  actual: {actual:?}",
+            list(&missing)
+        )
+    })
+}
+
+/// Asserts that the [`Dictionary`] contains all entries of the expected one.
+///
+/// Additional keys in the actual dictionary are allowed. The failure message lists each missing key and mismatched value.
+///
+/// ```no_run
+/// use gd_rehearse::itest::*;
+/// use godot::prelude::*;
+///
+/// #[gditest]
+/// fn dictionary_has_entries() {
+///     let actual = dict! { "name": "player", "hp": 100, "pos": Vector2::ZERO };
+///     assert_dict_contains!(actual, dict! { "name": "player", "hp": 100 });
+/// }
+/// ```
+#[macro_export]
+macro_rules! assert_dict_contains {
+    ($actual:expr, $expected:expr $(,)?) => {
+        if let Some(message) = $crate::assertions::collections::dict_contains(&$actual, &$expected) {
+            $crate::assertions::fail_assertion(format_args!("{message}"));
+        }
+    };
+    ($actual:expr, $expected:expr, $($arg:tt)+) => {
+        if let Some(message) = $crate::assertions::collections::dict_contains(&$actual, &$expected) {
+            $crate::assertions::fail_assertion(format_args!("{}: {message}", format_args!($($arg)+)));
+        }
+    };
+}
+
+/// Asserts that two [`Array`]s contain the same elements, regardless of their order.
+///
+/// The failure message lists elements missing from the actual array and the extra ones it contains.
+///
+/// ```no_run
+/// use gd_rehearse::itest::*;
+/// use godot::prelude::*;
+///
+/// #[gditest]
+/// fn arrays_have_same_elements() {
+///     let actual = array![3, 1, 2];
+///     assert_array_eq_unordered!(actual, array![1, 2, 3]);
+/// }
+/// ```
+#[macro_export]
+macro_rules! assert_array_eq_unordered {
+    ($actual:expr, $expected:expr $(,)?) => {
+        if let Some(message) = $crate::assertions::collections::array_eq_unordered(&$actual, &$expected) {
+            $crate::assertions::fail_assertion(format_args!("{message}"));
+        }
+    };
+    ($actual:expr, $expected:expr, $($arg:tt)+) => {
+        if let Some(message) = $crate::assertions::collections::array_eq_unordered(&$actual, &$expected) {
+            $crate::assertions::fail_assertion(format_args!("{}: {message}", format_args!($($arg)+)));
+        }
+    };
+}
+
+/// Asserts that the [`Array`] contains all elements of the expected one, in any order.
+///
+/// The failure message lists elements missing from the actual array.
+///
+/// ```no_run
+/// use gd_rehearse::itest::*;
+/// use godot::prelude::*;
+///
+/// #[gditest]
+/// fn array_has_elements() {
+///     let actual = varray![1, "two", 3.0];
+///     assert_array_contains!(actual, varray!["two"]);
+/// }
+/// ```
+#[macro_export]
+macro_rules! assert_array_contains {
+    ($actual:expr, $expected:expr $(,)?) => {
+        if let Some(message) = $crate::assertions::collections::array_contains(&$actual, &$expected) {
+            $crate::assertions::fail_assertion(format_args!("{message}"));
+        }
+    };
+    ($actual:expr, $expected:expr, $($arg:tt)+) => {
+        if let Some(message) = $crate::assertions::collections::array_contains(&$actual, &$expected) {
+            $crate::assertions::fail_assertion(format_args!("{}: {message}", format_args!($($arg)+)));
+        }
+    };
+}
@@ -0,0 +1,74 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Helpers backing the [`assert_eventually!`](crate::assert_eventually) macro.
+
+use std::time::{Duration, Instant};
+
+/// Interval between checks of the polled condition.
+const POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+/// Polls `condition` until it holds or `timeout` elapses, returning failure message if it never held.
+///
+/// The condition is always checked at least once, and once more after the timeout elapses.
+pub fn eventually(mut condition: impl FnMut() -> bool, timeout: Duration) -> Option<String> {
+    let start = Instant::now();
+    let mut polls = 0;
+    loop {
+        polls += 1;
+        if condition() {
+            return None;
+        }
+        let elapsed = start.elapsed();
+        if elapsed >= timeout {
+            return Some(format!(
+                "condition didn't hold within {:.3}s, checked {polls} times",
+                timeout.as_secs_f64()
+            ));
+        }
+        std::thread::sleep(POLL_INTERVAL.min(timeout - elapsed));
+    }
+}
+
+/// Asserts that the condition becomes true within the timeout in seconds, polling it every 10 milliseconds.
+///
+/// The test passes the assertion as soon as the condition holds. Tests run synchronously, so the engine doesn't process any
+/// frame while polling - the condition needs to be changed by other threads, e.g. a thread pool or `WorkerThreadPool` task.
+///
+/// ```no_run
+/// use std::sync::atomic::{AtomicBool, Ordering};
+/// use std::sync::Arc;
+///
+/// use gd_rehearse::itest::*;
+///
+/// #[gditest]
+/// fn background_work_finishes() {
+///     let done = Arc::new(AtomicBool::new(false));
+///     let flag = done.clone();
+///     std::thread::spawn(move || flag.store(true, Ordering::SeqCst));
+///
+///     assert_eventually!(done.load(Ordering::SeqCst), 1.0);
+/// }
+/// ```
+#[macro_export]
+macro_rules! assert_eventually {
+    ($condition:expr, $timeout_secs:expr $(,)?) => {
+        if let Some(message) = $crate::assertions::eventually::eventually(
+            || $condition,
+            ::std::time::Duration::from_secs_f64($timeout_secs as f64),
+        ) {
+            $crate::assertions::fail_assertion(format_args!("`{}`: {message}", stringify!($condition)));
+        }
+    };
+    ($condition:expr, $timeout_secs:expr, $($arg:tt)+) => {
+        if let Some(message) = $crate::assertions::eventually::eventually(
+            || $condition,
+            ::std::time::Duration::from_secs_f64($timeout_secs as f64),
+        ) {
+            $crate::assertions::fail_assertion(format_args!("{}: `{}`: {message}", format_args!($($arg)+), stringify!($condition)));
+        }
+    };
+}
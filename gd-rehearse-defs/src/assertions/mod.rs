@@ -0,0 +1,34 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Assertion helpers usable in `#[gditest]` and `#[gdbench]` functions.
+
+pub mod collections;
+pub mod eventually;
+pub mod panics;
+pub mod resource;
+pub mod soft;
+pub mod variant;
+
+use std::cell::Cell;
+
+thread_local! {
+    /// Set by the assertion macros of the crate right before they panic, so the panic can be told apart from other ones.
+    static ASSERTION_FAILED: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Panics with the `message`, marking the panic as an assertion failure. Used by the assertion macros of the crate.
+#[doc(hidden)]
+#[track_caller]
+pub fn fail_assertion(message: std::fmt::Arguments) -> ! {
+    ASSERTION_FAILED.with(|failed| failed.set(true));
+    panic!("{message}")
+}
+
+/// Checks whether the last panic on this thread was raised by [`fail_assertion`], resetting the marker.
+pub(crate) fn take_assertion_marker() -> bool {
+    ASSERTION_FAILED.with(|failed| failed.replace(false))
+}
@@ -0,0 +1,103 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Helpers backing the [`assert_panics!`](crate::assert_panics) and [`assert_panics_with!`](crate::assert_panics_with) macros.
+
+use std::panic::AssertUnwindSafe;
+
+use crate::runner::panic::unpack_err;
+
+/// Executes `code`, returning message of the panic it raised, or `None` if it didn't panic.
+///
+/// Panic hook is muted for the duration of the call, so the expected panic isn't printed like an unexpected one. Skip
+/// requests from [`skip_test`](crate::cases::rust_test_case::skip_test) are passed through, skipping the whole test.
+pub fn catch_panic_message<R>(code: impl FnOnce() -> R) -> Option<String> {
+    let hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(|_| {}));
+    let result = std::panic::catch_unwind(AssertUnwindSafe(code));
+    std::panic::set_hook(hook);
+
+    let payload = result.err()?;
+    let error = unpack_err(&*payload);
+    if error.is_skipped() {
+        std::panic::resume_unwind(payload);
+    }
+    Some(error.message().to_owned())
+}
+
+/// Checks that `code` panics, returning failure message if it didn't.
+pub fn panics<R>(code: impl FnOnce() -> R) -> Option<String> {
+    match catch_panic_message(code) {
+        Some(_) => None,
+        None => Some("expected a panic, but the code completed successfully".to_owned()),
+    }
+}
+
+/// Checks that `code` panics with a message containing `expected`, returning failure message if it didn't.
+pub fn panics_with<R>(code: impl FnOnce() -> R, expected: &str) -> Option<String> {
+    match catch_panic_message(code) {
+        Some(message) if message.contains(expected) => None,
+        Some(message) => Some(format!(
+            "expected a panic with message containing '{expected}', got: '{message}'"
+        )),
+        None => Some(format!(
+            "expected a panic with message containing '{expected}', but the code completed successfully"
+        )),
+    }
+}
+
+/// Asserts that the closure panics.
+///
+/// The caught panic doesn't fail the test and isn't printed. If the closure completes, the test fails instead.
+///
+/// ```no_run
+/// use gd_rehearse::itest::*;
+///
+/// #[gditest]
+/// fn out_of_bounds_panics() {
+///     let values: Vec<i32> = Vec::new();
+///     assert_panics!(|| values[0]);
+/// }
+/// ```
+#[macro_export]
+macro_rules! assert_panics {
+    ($code:expr $(,)?) => {
+        if let Some(message) = $crate::assertions::panics::panics($code) {
+            $crate::assertions::fail_assertion(format_args!("{message}"));
+        }
+    };
+    ($code:expr, $($arg:tt)+) => {
+        if let Some(message) = $crate::assertions::panics::panics($code) {
+            $crate::assertions::fail_assertion(format_args!("{}: {message}", format_args!($($arg)+)));
+        }
+    };
+}
+
+/// Asserts that the closure panics with a message containing the expected substring.
+///
+/// The failure message shows the actual panic message if it doesn't match.
+///
+/// ```no_run
+/// use gd_rehearse::itest::*;
+///
+/// #[gditest]
+/// fn parsing_invalid_number_panics() {
+///     assert_panics_with!(|| "abc".parse::<i32>().expect("invalid number"), "invalid number");
+/// }
+/// ```
+#[macro_export]
+macro_rules! assert_panics_with {
+    ($code:expr, $expected:expr $(,)?) => {
+        if let Some(message) = $crate::assertions::panics::panics_with($code, $expected) {
+            $crate::assertions::fail_assertion(format_args!("{message}"));
+        }
+    };
+    ($code:expr, $expected:expr, $($arg:tt)+) => {
+        if let Some(message) = $crate::assertions::panics::panics_with($code, $expected) {
+            $crate::assertions::fail_assertion(format_args!("{}: {message}", format_args!($($arg)+)));
+        }
+    };
+}
@@ -0,0 +1,156 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Helpers backing the [`assert_resource_matches!`](crate::assert_resource_matches) macro.
+//!
+//! Only properties stored in resource files are compared, and both resources need to have the same ones, so e.g. metadata
+//! set only on the produced resource fails the comparison. Values are compared as [`Variant`]s, except for nested resources,
+//! which are compared property by property as well, as loaded sub-resources are never the same objects as the produced ones.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use godot::builtin::{GString, StringName, Variant};
+use godot::engine::global::{Error, PropertyUsageFlags};
+use godot::engine::{Resource, ResourceLoader, ResourceSaver};
+use godot::obj::{EngineBitfield, Gd};
+
+/// Properties stored in the file, but describing the file rather than the resource itself.
+const IGNORED_PROPERTIES: &[&str] = &["resource_path", "resource_local_to_scene"];
+
+/// If set with `--update-snapshots`, [`resource_matches`] saves the actual resource instead of comparing it.
+static UPDATE_SNAPSHOTS: AtomicBool = AtomicBool::new(false);
+
+pub(crate) fn set_update_snapshots(update: bool) {
+    UPDATE_SNAPSHOTS.store(update, Ordering::SeqCst);
+}
+
+/// Checks that `actual` matches the resource stored at `expected_path`, e.g. `res://tests/golden/material.tres`.
+///
+/// Returns failure message naming the first mismatching property, or `None` if the check passed. Resource which can't be
+/// loaded is reported as a failure too. With `--update-snapshots`, `actual` is saved to `expected_path` instead, failing only
+/// if it can't be saved.
+pub fn resource_matches(actual: &Gd<Resource>, expected_path: &str) -> Option<String> {
+    if UPDATE_SNAPSHOTS.load(Ordering::SeqCst) {
+        return save_snapshot(actual, expected_path);
+    }
+    let Some(expected) = ResourceLoader::singleton().load(GString::from(expected_path)) else {
+        return Some(format!("couldn't load expected resource '{expected_path}'"));
+    };
+    first_mismatch(&expected, actual, "")
+        .map(|mismatch| format!("resource doesn't match '{expected_path}': {mismatch}"))
+}
+
+fn save_snapshot(actual: &Gd<Resource>, path: &str) -> Option<String> {
+    let error = ResourceSaver::singleton()
+        .save_ex(actual.clone())
+        .path(GString::from(path))
+        .done();
+    (error != Error::OK).then(|| format!("couldn't update snapshot '{path}': {error:?}"))
+}
+
+fn first_mismatch(expected: &Gd<Resource>, actual: &Gd<Resource>, prefix: &str) -> Option<String> {
+    let (expected_class, actual_class) = (expected.get_class(), actual.get_class());
+    if expected_class != actual_class {
+        let location = if prefix.is_empty() {
+            String::new()
+        } else {
+            format!("property '{prefix}': ")
+        };
+        return Some(format!(
+            "{location}expected resource of class {expected_class}, got {actual_class}"
+        ));
+    }
+
+    let path = |name: &str| {
+        if prefix.is_empty() {
+            name.to_owned()
+        } else {
+            format!("{prefix}.{name}")
+        }
+    };
+    let (expected_properties, actual_properties) =
+        (stored_properties(expected), stored_properties(actual));
+    for name in &expected_properties {
+        let expected_value = expected.get(StringName::from(name));
+        if !actual_properties.contains(name) {
+            return Some(format!(
+                "property '{}' is missing, expected: {expected_value}",
+                path(name)
+            ));
+        }
+        let actual_value = actual.get(StringName::from(name));
+        if let Some(mismatch) = value_mismatch(&expected_value, &actual_value, &path(name)) {
+            return Some(mismatch);
+        }
+    }
+    // Resources of the same class can still differ in properties, e.g. in metadata or in the ones of attached scripts.
+    let unexpected = actual_properties
+        .iter()
+        .find(|name| !expected_properties.contains(name))?;
+    Some(format!(
+        "unexpected property '{}', got: {}",
+        path(unexpected),
+        actual.get(StringName::from(unexpected))
+    ))
+}
+
+/// Names of the properties stored in the resource file, without [`IGNORED_PROPERTIES`].
+fn stored_properties(resource: &Gd<Resource>) -> Vec<String> {
+    resource
+        .get_property_list()
+        .iter_shared()
+        .filter(|property| {
+            let usage = property.get_or_nil("usage").try_to::<u64>().unwrap_or(0);
+            usage & PropertyUsageFlags::STORAGE.ord() != 0
+        })
+        .map(|property| property.get_or_nil("name").to_string())
+        .filter(|name| !IGNORED_PROPERTIES.contains(&name.as_str()))
+        .collect()
+}
+
+fn value_mismatch(expected: &Variant, actual: &Variant, path: &str) -> Option<String> {
+    match (
+        expected.try_to::<Gd<Resource>>(),
+        actual.try_to::<Gd<Resource>>(),
+    ) {
+        (Ok(expected), Ok(actual)) => first_mismatch(&expected, &actual, path),
+        _ if expected == actual => None,
+        _ => Some(format!(
+            "property '{path}' differs, expected: {expected}, got: {actual}"
+        )),
+    }
+}
+
+/// Asserts that the [`Resource`] matches the one stored in the resource file, e.g. a committed `.tres` golden file.
+///
+/// The failure message names the first mismatching property, along with both values. Resource file which can't be loaded
+/// fails the assertion as well. Run with `--update-snapshots` to save the actual resource to the file instead, e.g. after an
+/// intended change.
+///
+/// ```no_run
+/// use gd_rehearse::itest::*;
+/// use godot::engine::{Gradient, Resource};
+/// use godot::obj::{Gd, NewGd};
+///
+/// #[gditest]
+/// fn generated_gradient_matches() {
+///     let gradient = Gradient::new_gd();
+///     assert_resource_matches!(gradient.upcast::<Resource>(), "res://golden/gradient.tres");
+/// }
+/// ```
+#[macro_export]
+macro_rules! assert_resource_matches {
+    ($actual:expr, $expected_path:expr $(,)?) => {
+        if let Some(message) = $crate::assertions::resource::resource_matches(&$actual, $expected_path) {
+            $crate::assertions::fail_assertion(format_args!("{message}"));
+        }
+    };
+    ($actual:expr, $expected_path:expr, $($arg:tt)+) => {
+        if let Some(message) = $crate::assertions::resource::resource_matches(&$actual, $expected_path) {
+            $crate::assertions::fail_assertion(format_args!("{}: {message}", format_args!($($arg)+)));
+        }
+    };
+}
@@ -0,0 +1,121 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::cell::RefCell;
+use std::fmt::{Debug, Display};
+use std::panic::Location;
+
+use crate::runner::relative_path;
+
+/// Collector of soft assertion failures, provided by [`soft_assertions`].
+///
+/// In contrast to `assert!` family of macros, failed checks don't stop the test - they are recorded, and the test fails
+/// after the whole [`soft_assertions`] scope finishes, listing all of them.
+pub struct SoftAssertions {
+    failures: RefCell<Vec<String>>,
+}
+
+impl SoftAssertions {
+    /// Records a failure with the given `message` if `condition` is `false`.
+    #[track_caller]
+    pub fn check(&self, condition: bool, message: impl Display) {
+        if !condition {
+            self.record(message.to_string(), Location::caller());
+        }
+    }
+
+    /// Records a failure if `left` and `right` are not equal.
+    #[track_caller]
+    pub fn check_eq<T: PartialEq + Debug>(&self, left: T, right: T) {
+        if left != right {
+            self.record(
+                format!("`left == right` failed, left: {left:?}, right: {right:?}"),
+                Location::caller(),
+            );
+        }
+    }
+
+    /// Records a failure if `left` and `right` are equal.
+    #[track_caller]
+    pub fn check_ne<T: PartialEq + Debug>(&self, left: T, right: T) {
+        if left == right {
+            self.record(
+                format!("`left != right` failed, left: {left:?}, right: {right:?}"),
+                Location::caller(),
+            );
+        }
+    }
+
+    /// Unconditionally records a failure with the given `message`.
+    #[track_caller]
+    pub fn fail(&self, message: impl Display) {
+        self.record(message.to_string(), Location::caller());
+    }
+
+    /// Number of failures recorded so far.
+    pub fn failures_count(&self) -> usize {
+        self.failures.borrow().len()
+    }
+
+    fn record(&self, message: String, location: &Location) {
+        self.failures.borrow_mut().push(format!(
+            "{message} (at {}:{})",
+            relative_path(location.file()),
+            location.line()
+        ));
+    }
+}
+
+/// Payload of the panic raised by [`soft_assertions`] scope with failed checks.
+pub(crate) struct SoftAssertionFailures {
+    pub failures: Vec<String>,
+}
+
+impl SoftAssertionFailures {
+    pub fn message(&self) -> String {
+        let count = self.failures.len();
+        let noun = if count == 1 {
+            "soft assertion"
+        } else {
+            "soft assertions"
+        };
+        format!(
+            "assertion failed: {count} {noun} failed:\n  - {}",
+            self.failures.join("\n  - ")
+        )
+    }
+}
+
+/// Executes `checks` with a [`SoftAssertions`] collector, failing the test afterwards if any of the checks failed.
+///
+/// All failures are reported together, each of them listed as a separate item in the test summary. A regular panic inside
+/// `checks` still stops the test immediately.
+///
+/// ```no_run
+/// use gd_rehearse::itest::*;
+///
+/// #[gditest]
+/// fn produced_object_is_valid() {
+///     let values = vec![1, 2, 3];
+///     soft_assertions(|ctx| {
+///         ctx.check_eq(values.len(), 3);
+///         ctx.check(values.contains(&2), "missing 2");
+///         ctx.check_ne(values[0], 0);
+///     });
+/// }
+/// ```
+pub fn soft_assertions<R>(checks: impl FnOnce(&SoftAssertions) -> R) -> R {
+    let ctx = SoftAssertions {
+        failures: RefCell::new(Vec::new()),
+    };
+    let result = checks(&ctx);
+    let failures = ctx.failures.into_inner();
+    if !failures.is_empty() {
+        // Resuming unwind directly omits the panic hook, as the failures are reported by the runner.
+        std::panic::resume_unwind(Box::new(SoftAssertionFailures { failures }));
+    }
+    result
+}
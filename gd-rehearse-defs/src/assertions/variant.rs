@@ -0,0 +1,87 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Helpers backing the [`assert_variant_type!`](crate::assert_variant_type) and
+//! [`assert_variant_is_nil!`](crate::assert_variant_is_nil) macros.
+
+use godot::builtin::{Variant, VariantType};
+
+/// Checks that `value` holds a value of the `expected` type.
+///
+/// Returns failure message naming the expected and actual types, or `None` if the check passed.
+pub fn variant_type(value: &Variant, expected: VariantType) -> Option<String> {
+    let actual = value.get_type();
+    (actual != expected)
+        .then(|| format!("expected variant of type {expected:?}, got {actual:?}: {value}"))
+}
+
+/// Checks that `value` is nil.
+///
+/// Returns failure message naming the actual type, or `None` if the check passed.
+pub fn variant_is_nil(value: &Variant) -> Option<String> {
+    (!value.is_nil()).then(|| {
+        format!(
+            "expected variant of type NIL, got {:?}: {value}",
+            value.get_type()
+        )
+    })
+}
+
+/// Asserts that the [`Variant`] holds a value of the given [`VariantType`].
+///
+/// The failure message names the expected and the actual type, along with the value.
+///
+/// ```no_run
+/// use gd_rehearse::itest::*;
+/// use godot::prelude::*;
+///
+/// #[gditest]
+/// fn variant_holds_string() {
+///     let value = "player".to_variant();
+///     assert_variant_type!(value, VariantType::STRING);
+/// }
+/// ```
+#[macro_export]
+macro_rules! assert_variant_type {
+    ($value:expr, $expected:expr $(,)?) => {
+        if let Some(message) = $crate::assertions::variant::variant_type(&$value, $expected) {
+            $crate::assertions::fail_assertion(format_args!("{message}"));
+        }
+    };
+    ($value:expr, $expected:expr, $($arg:tt)+) => {
+        if let Some(message) = $crate::assertions::variant::variant_type(&$value, $expected) {
+            $crate::assertions::fail_assertion(format_args!("{}: {message}", format_args!($($arg)+)));
+        }
+    };
+}
+
+/// Asserts that the [`Variant`] is nil.
+///
+/// The failure message names the actual type, along with the value.
+///
+/// ```no_run
+/// use gd_rehearse::itest::*;
+/// use godot::prelude::*;
+///
+/// #[gditest]
+/// fn missing_key_is_nil() {
+///     let dict = dict! { "hp": 100 };
+///     assert_variant_is_nil!(dict.get_or_nil("mana"));
+/// }
+/// ```
+#[macro_export]
+macro_rules! assert_variant_is_nil {
+    ($value:expr $(,)?) => {
+        if let Some(message) = $crate::assertions::variant::variant_is_nil(&$value) {
+            $crate::assertions::fail_assertion(format_args!("{message}"));
+        }
+    };
+    ($value:expr, $($arg:tt)+) => {
+        if let Some(message) = $crate::assertions::variant::variant_is_nil(&$value) {
+            $crate::assertions::fail_assertion(format_args!("{}: {message}", format_args!($($arg)+)));
+        }
+    };
+}
@@ -9,11 +9,20 @@ pub mod rust_bench;
 pub mod rust_test_case;
 
 use std::cmp::Ordering;
+use std::sync::RwLock;
+
+use serde::{Deserialize, Serialize};
 
 use godot::builtin::NodePath;
 use godot::engine::{Engine, Node};
 use godot::obj::{Gd, Inherits};
 
+use crate::runner::config::FiltersCombination;
+use crate::runner::extract_file_subtitle;
+use crate::runner::glob::GlobPattern;
+use crate::runner::panic::UnwindError;
+use crate::runner::shuffle::name_hash;
+
 // /// Optional test context for `#[gditest]` and `#[gdbench]` annotated functions.
 // ///
 // /// Currently it allows only to access [GdTestRunner](crate::runner::GdTestRunner) scene tree during tests and benchmarking.
@@ -60,6 +69,19 @@ pub trait CaseContext {
     }
 }
 
+/// Function transforming case names for output and reports.
+pub(crate) type DisplayNameHook = fn(&str) -> String;
+
+static DISPLAY_NAME_HOOK: RwLock<Option<DisplayNameHook>> = RwLock::new(None);
+
+/// Sets function transforming names of the cases for output and reports, set with
+/// [`GdTestRunner::set_display_name_hook`](crate::runner::GdTestRunner::set_display_name_hook).
+pub(crate) fn set_display_name_hook(hook: Option<DisplayNameHook>) {
+    *DISPLAY_NAME_HOOK
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner()) = hook;
+}
+
 /// Case outcome.
 #[derive(PartialEq)]
 #[must_use]
@@ -91,6 +113,71 @@ impl std::fmt::Display for CaseOutcome {
     }
 }
 
+/// Category of case failure.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FailureKind {
+    Assertion,
+    Panic,
+    Setup,
+    Cleanup,
+    Leak,
+    Regression,
+    Timeout,
+}
+
+impl FailureKind {
+    /// Failures of the assertion macros of the crate and of `assert!` family of macros are distinguished from other panics.
+    pub(crate) fn from_unwind(error: &UnwindError) -> Self {
+        if error.is_assertion() {
+            Self::Assertion
+        } else {
+            Self::Panic
+        }
+    }
+
+    pub(crate) fn from_arg(value: &str) -> Option<Self> {
+        match value {
+            "assertion" => Some(Self::Assertion),
+            "panic" => Some(Self::Panic),
+            "setup" => Some(Self::Setup),
+            "cleanup" => Some(Self::Cleanup),
+            "leak" => Some(Self::Leak),
+            "regression" => Some(Self::Regression),
+            "timeout" => Some(Self::Timeout),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn for_summary(&self, count: usize) -> String {
+        let (singular, plural) = match self {
+            FailureKind::Assertion => ("assertion failure", "assertion failures"),
+            FailureKind::Panic => ("panic", "panics"),
+            FailureKind::Setup => ("setup error", "setup errors"),
+            FailureKind::Cleanup => ("cleanup error", "cleanup errors"),
+            FailureKind::Leak => ("leak", "leaks"),
+            FailureKind::Regression => ("regression", "regressions"),
+            FailureKind::Timeout => ("timeout", "timeouts"),
+        };
+        format!("{count} {}", if count == 1 { singular } else { plural })
+    }
+}
+
+impl std::fmt::Display for FailureKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let kind = match self {
+            FailureKind::Assertion => "assertion",
+            FailureKind::Panic => "panic",
+            FailureKind::Setup => "setup",
+            FailureKind::Cleanup => "cleanup",
+            FailureKind::Leak => "leak",
+            FailureKind::Regression => "regression",
+            FailureKind::Timeout => "timeout",
+        };
+        f.write_str(kind)
+    }
+}
+
 /// Disable printing errors from Godot. Ideally we should catch and handle errors, ensuring they happen when
 /// expected. But that isn't possible, so for now we can just disable printing the error to avoid spamming
 /// the terminal when tests should error.
@@ -103,14 +190,44 @@ pub fn suppress_godot_print(mut f: impl FnMut()) {
 pub(crate) trait Case {
     fn is_case_focus(&self) -> bool;
     fn is_case_skip(&self) -> bool;
+    /// Only smoke cases are executed during `--smoke` run.
+    fn is_case_smoke(&self) -> bool {
+        false
+    }
     fn get_case_keyword(&self) -> &Option<&str>;
     fn get_case_name(&self) -> &str;
+    /// Name set with `display_name` attribute, replacing the canonical one in output and reports.
+    fn get_case_display_override(&self) -> Option<&str> {
+        None
+    }
     fn get_case_line(&self) -> u32;
     fn get_case_file(&self) -> &str;
     fn get_case_scene_path(&self) -> &Option<&str>;
+    fn get_case_description(&self) -> &Option<&str>;
+    fn get_case_priority(&self) -> i32;
 
+    // Cases are popped from the end, so the ones with the highest priority, and then first in file, are sorted last.
     fn order(first: &Self, other: &Self) -> Ordering {
-        other.get_order_string().cmp(&first.get_order_string())
+        first
+            .get_case_priority()
+            .cmp(&other.get_case_priority())
+            .then_with(|| other.get_order_string().cmp(&first.get_order_string()))
+            .then_with(|| other.get_case_name().cmp(first.get_case_name()))
+    }
+
+    /// Name shown in output and reports: the `display_name` override or the canonical name, passed through the display
+    /// name hook if set. Filters and everything identifying the case across runs use the canonical name.
+    fn get_case_display_name(&self) -> String {
+        let name = self
+            .get_case_display_override()
+            .unwrap_or(self.get_case_name());
+        let hook = *DISPLAY_NAME_HOOK
+            .read()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        match hook {
+            Some(hook) => hook(name),
+            None => name.to_owned(),
+        }
     }
 
     fn get_order_string(&self) -> String {
@@ -121,6 +238,10 @@ pub(crate) trait Case {
         !is_focus_run || self.is_case_focus()
     }
 
+    fn should_run_smoke(&self, is_smoke_run: bool) -> bool {
+        !is_smoke_run || self.is_case_smoke()
+    }
+
     fn should_run_skip(&self, disallow_skip: bool) -> bool {
         !self.is_case_skip() || disallow_skip
     }
@@ -137,13 +258,60 @@ pub(crate) trait Case {
         false
     }
 
-    fn should_run_filters(&self, filters: &[String]) -> bool {
+    fn should_run_filters(&self, filters: &[String], mode: FiltersCombination) -> bool {
+        if filters.is_empty() {
+            return true;
+        };
+        let mut matching = filters
+            .iter()
+            .map(|filter| self.get_case_name().contains(filter.as_str()));
+        match mode {
+            FiltersCombination::Or => matching.any(|matches| matches),
+            FiltersCombination::And => matching.all(|matches| matches),
+        }
+    }
+
+    fn should_run_glob_filters(&self, filters: &[GlobPattern]) -> bool {
         if filters.is_empty() {
             return true;
         };
         filters
             .iter()
-            .any(|filter| self.get_case_name().contains(filter))
+            .any(|filter| filter.matches(self.get_case_name()))
+    }
+
+    // Suite is the file the case is defined in, named with or without the `.rs` extension
+    fn is_in_suite(&self, suite: &str) -> bool {
+        let file = extract_file_subtitle(self.get_case_file());
+        file == suite || file.strip_suffix(".rs") == Some(suite)
+    }
+
+    fn should_run_suites(&self, suites: &[String]) -> bool {
+        suites.is_empty() || suites.iter().any(|suite| self.is_in_suite(suite))
+    }
+
+    fn should_run_priority(&self, priority_threshold: Option<i32>) -> bool {
+        priority_threshold.is_none_or(|threshold| self.get_case_priority() >= threshold)
+    }
+
+    /// Checks if the case belongs to the `(index, count)` shard, with index starting at 1.
+    fn should_run_shard(&self, shard: Option<(u64, u64)>) -> bool {
+        shard.is_none_or(|(index, count)| {
+            let name = format!(
+                "{}::{}",
+                extract_file_subtitle(self.get_case_file()),
+                self.get_case_name()
+            );
+            name_hash(&name) % count == index - 1
+        })
+    }
+
+    fn should_run_description_filters(&self, filters: &[String]) -> bool {
+        if filters.is_empty() {
+            return true;
+        };
+        self.get_case_description()
+            .is_some_and(|description| filters.iter().any(|filter| description.contains(filter)))
     }
 
     fn should_run_scene_path(&self, scene_path: &str, is_path_run: bool) -> bool {
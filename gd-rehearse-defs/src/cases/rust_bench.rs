@@ -15,9 +15,11 @@ use godot::builtin::{GString, NodePath};
 use godot::engine::Node;
 use godot::obj::{Gd, Inherits};
 
+use crate::runner::baseline::BenchComparison;
+use crate::runner::format_percent;
 use crate::runner::panic::{unwind_result, UnwindError, UnwindResult};
 
-use super::{Case, CaseContext};
+use super::{Case, CaseContext, FailureKind};
 
 /// Rust benchmark.
 ///
@@ -30,12 +32,14 @@ pub struct RustBenchmark {
     pub focused: bool,
     pub keyword: Option<&'static str>,
     pub scene_path: Option<&'static str>,
-    #[allow(dead_code)]
     pub line: u32,
     pub function: fn(&BenchContext),
     pub setup_function: Option<fn(&mut BenchContext)>,
     pub cleanup_function: Option<fn(&mut BenchContext)>,
     pub repetitions: usize,
+    pub description: Option<&'static str>,
+    pub priority: i32,
+    pub group: Option<&'static str>,
 }
 
 impl Case for RustBenchmark {
@@ -60,6 +64,12 @@ impl Case for RustBenchmark {
     fn get_case_line(&self) -> u32 {
         self.line
     }
+    fn get_case_description(&self) -> &Option<&str> {
+        &self.description
+    }
+    fn get_case_priority(&self) -> i32 {
+        self.priority
+    }
 }
 
 impl RustBenchmark {
@@ -282,6 +292,21 @@ pub(crate) enum BenchError {
     Setup(UnwindError),
     Execution(UnwindError),
     Cleanup(CleanupError),
+    Regression(BenchComparison),
+    Timeout(String),
+}
+
+impl BenchError {
+    pub fn failure_kind(&self) -> FailureKind {
+        match self {
+            BenchError::Setup(_) => FailureKind::Setup,
+            BenchError::Execution(err) => FailureKind::from_unwind(err),
+            BenchError::Cleanup(err) if err.not_cleaned => FailureKind::Leak,
+            BenchError::Cleanup(_) => FailureKind::Cleanup,
+            BenchError::Regression(_) => FailureKind::Regression,
+            BenchError::Timeout(_) => FailureKind::Timeout,
+        }
+    }
 }
 
 impl Display for BenchError {
@@ -290,12 +315,22 @@ impl Display for BenchError {
             BenchError::Setup(err) => write!(f, "[setup] {err}"),
             BenchError::Execution(err) => write!(f, "[execution] {err}"),
             BenchError::Cleanup(err) => write!(f, "[cleanup] {err}"),
+            BenchError::Regression(comparison) => write!(
+                f,
+                "[regression] median {:.3}μs is {} slower than baseline {:.3}μs (fail threshold: {})",
+                comparison.current.as_nanos() as f64 / 1000.0,
+                format_percent(comparison.delta_pct),
+                comparison.baseline.as_nanos() as f64 / 1000.0,
+                format_percent(comparison.fail_pct.unwrap_or_default())
+            ),
+            BenchError::Timeout(message) => write!(f, "[timeout] {message}"),
         }
     }
 }
 
 impl Error for BenchError {}
 
+#[allow(dead_code)]
 #[derive(Debug)]
 pub(crate) struct SetupError {
     cause: UnwindError,
@@ -4,9 +4,18 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
 */
 
+use std::fmt::Display;
+use std::net::{TcpStream, ToSocketAddrs};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::{Duration, Instant};
+
 use godot::engine::Node;
 use godot::obj::Gd;
 
+use crate::runner::extract_file_subtitle;
+use crate::runner::shuffle::SeededRng;
+
 use super::{Case, CaseContext};
 
 /// Rust test case.
@@ -19,18 +28,85 @@ pub struct RustTestCase {
     pub skipped: bool,
     /// If one or more tests are focused, only they will be executed. Helpful for debugging and working on specific features.
     pub focused: bool,
+    /// Part of the quick subset of tests executed during `--smoke` run.
+    pub smoke: bool,
     /// Used in conjuction with set
     pub keyword: Option<&'static str>,
     pub scene_path: Option<&'static str>,
-    #[allow(dead_code)]
     pub line: u32,
     pub function: fn(&TestContext),
+    /// Human-readable description, matched by description filters.
+    pub description: Option<&'static str>,
+    /// Name shown in output and reports instead of `name`, which is still used by the filters.
+    pub display_name: Option<&'static str>,
+    /// Tests with higher priority are executed first.
+    pub priority: i32,
+    /// Timeout in seconds, overriding the global one. `0` means no timeout.
+    pub timeout: Option<f64>,
+    /// Tests which need to pass before this one is run, as `test_name` or `file.rs::test_name`.
+    pub depends_on: &'static [&'static str],
+    /// Retries after failure, overriding the global count.
+    pub retries: Option<u32>,
+    /// Mode of the run the test requires. In other mode the test is skipped.
+    pub requires: Option<RunMode>,
+}
+
+/// Mode of the run, which can be required by the test with `requires` attribute of `#[gditest]`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum RunMode {
+    /// Run without a window, eg. with `--headless` command line argument.
+    Headless,
+    /// Run with a window, eg. started from the Godot editor. It's still a separate game process, not the editor itself.
+    Windowed,
+}
+
+impl RunMode {
+    pub(crate) fn is_headless(&self) -> bool {
+        matches!(self, RunMode::Headless)
+    }
+}
+
+impl Display for RunMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RunMode::Headless => f.write_str("headless"),
+            RunMode::Windowed => f.write_str("windowed"),
+        }
+    }
+}
+
+impl RustTestCase {
+    /// Checks if the test is the one referenced by a `depends_on` entry.
+    pub(crate) fn is_dependency(&self, dependency: &str) -> bool {
+        match dependency.split_once("::") {
+            Some((file, name)) => extract_file_subtitle(self.file) == file && self.name == name,
+            None => self.name == dependency,
+        }
+    }
+
+    pub(crate) fn full_name(&self) -> String {
+        format!("{}::{}", extract_file_subtitle(self.file), self.name)
+    }
+
+    /// Timeout applying to the test, with its source: its own one or the `global` one. `None` if there is no timeout at all,
+    /// including the one disabled with `0`.
+    pub(crate) fn effective_timeout(&self, global: Option<f64>) -> Option<(f64, &'static str)> {
+        let (timeout, source) = match (self.timeout, global) {
+            (Some(timeout), _) => (timeout, "per-test timeout"),
+            (None, Some(timeout)) => (timeout, "global timeout"),
+            (None, None) => return None,
+        };
+        (timeout != 0.0).then_some((timeout, source))
+    }
 }
 
 impl Case for RustTestCase {
     fn get_case_name(&self) -> &str {
         self.name
     }
+    fn get_case_display_override(&self) -> Option<&str> {
+        self.display_name
+    }
     fn get_case_file(&self) -> &str {
         self.file
     }
@@ -40,6 +116,9 @@ impl Case for RustTestCase {
     fn is_case_skip(&self) -> bool {
         self.skipped
     }
+    fn is_case_smoke(&self) -> bool {
+        self.smoke
+    }
     fn get_case_keyword(&self) -> &Option<&str> {
         &self.keyword
     }
@@ -49,6 +128,12 @@ impl Case for RustTestCase {
     fn get_case_line(&self) -> u32 {
         self.line
     }
+    fn get_case_description(&self) -> &Option<&str> {
+        &self.description
+    }
+    fn get_case_priority(&self) -> i32 {
+        self.priority
+    }
 }
 
 /// Optional test context for `#[gditest]`.
@@ -56,11 +141,80 @@ impl Case for RustTestCase {
 /// Allows accessing [GdTestRunner](crate::runner::GdTestRunner) scene tree during tests.
 pub struct TestContext {
     pub(crate) scene_tree: Gd<Node>,
+    seed: u64,
+    custom_args: Arc<Vec<(String, String)>>,
+    /// Start of the current attempt, and the last checkpoint label with time since the start.
+    progress: Mutex<(Instant, Option<(String, Duration)>)>,
 }
 
 impl TestContext {
-    pub(crate) fn new(scene_tree: Gd<Node>) -> Self {
-        Self { scene_tree }
+    pub(crate) fn new(
+        scene_tree: Gd<Node>,
+        seed: u64,
+        custom_args: Arc<Vec<(String, String)>>,
+    ) -> Self {
+        Self {
+            scene_tree,
+            seed,
+            custom_args,
+            progress: Mutex::new((Instant::now(), None)),
+        }
+    }
+
+    fn progress(&self) -> MutexGuard<'_, (Instant, Option<(String, Duration)>)> {
+        // Poisoning by test panic doesn't invalidate the recorded progress.
+        self.progress
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Resets the checkpoint before every attempt of the test.
+    pub(crate) fn start_attempt(&self) {
+        *self.progress() = (Instant::now(), None);
+    }
+
+    pub(crate) fn last_checkpoint(&self) -> Option<(String, Duration)> {
+        self.progress().1.clone()
+    }
+
+    /// Records progress of the test. If the test exceeds its timeout, the last reached checkpoint is included in the failure
+    /// message, hinting which part of the test took too long.
+    ///
+    /// ```no_run
+    /// use gd_rehearse::itest::*;
+    ///
+    /// #[gditest(timeout = 5)]
+    /// fn loads_level(ctx: &TestContext) {
+    ///     ctx.checkpoint("level loaded");
+    ///     ctx.checkpoint("enemies spawned");
+    /// }
+    /// ```
+    pub fn checkpoint(&self, label: impl Into<String>) {
+        let mut progress = self.progress();
+        progress.1 = Some((label.into(), progress.0.elapsed()));
+    }
+
+    /// Seed of the current test, derived from the run seed printed at the start of the run and the test name.
+    ///
+    /// For test randomness to be reproducible with `--seed`, it needs to be derived from this seed or [`Self::rng`], instead of
+    /// a freshly seeded generator.
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Generator seeded with [`Self::seed`]. Every call returns a generator starting from the same state.
+    pub fn rng(&self) -> SeededRng {
+        SeededRng::new(self.seed)
+    }
+
+    /// Value of the custom command line argument declared with
+    /// [`GdTestRunner::add_custom_arg`](crate::runner::GdTestRunner::add_custom_arg), if it was provided. Flags without
+    /// value have an empty one.
+    pub fn custom_arg(&self, name: &str) -> Option<&str> {
+        self.custom_args
+            .iter()
+            .find(|(arg, _)| arg == name)
+            .map(|(_, value)| value.as_str())
     }
 }
 
@@ -69,3 +223,175 @@ impl CaseContext for TestContext {
         &self.scene_tree
     }
 }
+
+/// Payload of the unwind started by [`skip_test`].
+pub(crate) struct SkipRequest {
+    pub reason: String,
+}
+
+/// Stops the current test, marking it as skipped with the `reason`, instead of as passed or failed.
+///
+/// Allows skipping tests depending on conditions known only at runtime, e.g. available hardware. If `--disallow-skip` is
+/// provided, the test fails instead.
+///
+/// ```no_run
+/// use gd_rehearse::itest::*;
+///
+/// #[gditest]
+/// fn needs_gpu() {
+///     if godot::engine::RenderingServer::singleton().get_video_adapter_name().is_empty() {
+///         skip_test("no GPU detected");
+///     }
+/// }
+/// ```
+pub fn skip_test(reason: impl Into<String>) -> ! {
+    std::panic::resume_unwind(Box::new(SkipRequest {
+        reason: reason.into(),
+    }))
+}
+
+/// If set with `--require-services`, [`require_service`] fails the test instead of skipping it.
+static REQUIRE_SERVICES: AtomicBool = AtomicBool::new(false);
+
+pub(crate) fn set_require_services(require: bool) {
+    REQUIRE_SERVICES.store(require, Ordering::SeqCst);
+}
+
+/// Time for a single connection attempt made by [`require_service`].
+const SERVICE_CONNECT_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Skips the current test if the service under the `address` doesn't accept TCP connections.
+///
+/// Meant to be called at the start of integration tests depending on external services, which may be down during local
+/// development. If `--require-services` or `--disallow-skip` is provided, the test fails instead.
+///
+/// Connection timeout of 2 seconds applies to every address the `address` resolves to, so e.g. unreachable `localhost`,
+/// resolving to both IPv6 and IPv4 address, takes about 4 seconds to be reported.
+///
+/// ```no_run
+/// use gd_rehearse::itest::*;
+///
+/// #[gditest]
+/// fn fetches_from_local_api() {
+///     require_service("localhost:8080");
+///     // ...
+/// }
+/// ```
+pub fn require_service(address: &str) {
+    let Err(err) = connect_service(address) else {
+        return;
+    };
+    let message = format!("required service '{address}' is unreachable: {err}");
+    if REQUIRE_SERVICES.load(Ordering::SeqCst) {
+        panic!("{message}");
+    }
+    skip_test(message);
+}
+
+fn connect_service(address: &str) -> std::io::Result<()> {
+    let mut last_err = std::io::Error::new(
+        std::io::ErrorKind::NotFound,
+        "address didn't resolve to anything",
+    );
+    for addr in address.to_socket_addrs()? {
+        match TcpStream::connect_timeout(&addr, SERVICE_CONNECT_TIMEOUT) {
+            Ok(_) => return Ok(()),
+            Err(err) => last_err = err,
+        }
+    }
+    Err(last_err)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::TcpListener;
+
+    use crate::runner::panic::{unpack_err, UnwindError};
+
+    use super::{require_service, set_require_services, RustTestCase};
+
+    fn test_with_timeout(timeout: Option<f64>) -> RustTestCase {
+        RustTestCase {
+            name: "timed_test",
+            file: "itest.rs",
+            skipped: false,
+            focused: false,
+            smoke: false,
+            keyword: None,
+            scene_path: None,
+            line: 1,
+            function: |_| {},
+            description: None,
+            display_name: None,
+            priority: 0,
+            timeout,
+            depends_on: &[],
+            retries: None,
+            requires: None,
+        }
+    }
+
+    #[test]
+    fn per_test_timeout_overrides_global() {
+        let test = test_with_timeout(Some(2.5));
+        assert_eq!(
+            test.effective_timeout(Some(10.)),
+            Some((2.5, "per-test timeout"))
+        );
+        assert_eq!(
+            test.effective_timeout(None),
+            Some((2.5, "per-test timeout"))
+        );
+    }
+
+    #[test]
+    fn zero_per_test_timeout_disables_global() {
+        let test = test_with_timeout(Some(0.));
+        assert_eq!(test.effective_timeout(Some(10.)), None);
+    }
+
+    #[test]
+    fn global_timeout_applies_without_per_test_one() {
+        let test = test_with_timeout(None);
+        assert_eq!(
+            test.effective_timeout(Some(10.)),
+            Some((10., "global timeout"))
+        );
+        assert_eq!(test.effective_timeout(None), None);
+    }
+
+    /// Address nothing listens on, as its listener is closed right away.
+    fn closed_address() -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        listener.local_addr().unwrap().to_string()
+    }
+
+    fn unpacked_require_service(address: &str) -> UnwindError {
+        let hook = std::panic::take_hook();
+        std::panic::set_hook(Box::new(|_| {}));
+        let payload = std::panic::catch_unwind(|| require_service(address))
+            .expect_err("unreachable service should unwind");
+        std::panic::set_hook(hook);
+        unpack_err(&*payload)
+    }
+
+    // Both paths in a single test, as tests run in parallel and share the flag of `--require-services`.
+    #[test]
+    fn unreachable_service_skips_test_or_fails_with_require_services() {
+        let address = closed_address();
+        let skipped = unpacked_require_service(&address);
+        // Skipped tests are failed by the runner with `--disallow-skip`.
+        assert!(skipped.is_skipped(), "{}", skipped.message());
+        assert!(skipped.message().contains("is unreachable"));
+
+        set_require_services(true);
+        let failed = unpacked_require_service(&address);
+        set_require_services(false);
+        assert!(!failed.is_skipped());
+        assert!(
+            failed.message().contains("is unreachable"),
+            "{}",
+            failed.message()
+        );
+    }
+}
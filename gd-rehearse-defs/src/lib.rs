@@ -4,6 +4,7 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
+pub mod assertions;
 pub mod cases;
 pub mod registry;
 pub mod runner;
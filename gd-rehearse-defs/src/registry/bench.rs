@@ -7,7 +7,9 @@
 use std::time::Duration;
 
 use crate::cases::rust_bench::{BenchError, RustBenchmark};
-use crate::cases::CaseOutcome;
+use crate::cases::{CaseOutcome, FailureKind};
+use crate::runner::baseline::BenchComparison;
+use crate::runner::config::{CaseOrder, RunnerConfig};
 
 use super::CaseFilterer;
 
@@ -21,11 +23,18 @@ godot::sys::plugin_registry!(pub GD_REHEARSE_RUST_BENCHMARKS: RustBenchmark);
 pub(crate) struct GdBenchmarks {
     benches: Vec<RustBenchmark>,
     files_count: usize,
+    deselected: Vec<(&'static str, usize)>,
     is_focus_run: bool,
+    #[allow(dead_code)]
     is_path_run: bool,
 }
 
 impl GdBenchmarks {
+    /// Selected benchmarks, in order of execution.
+    pub fn benches(&self) -> impl Iterator<Item = &RustBenchmark> {
+        self.benches.iter().rev()
+    }
+
     pub fn bench_count(&self) -> usize {
         self.benches.len()
     }
@@ -38,6 +47,7 @@ impl GdBenchmarks {
         let mut instance = Self {
             benches: Vec::new(),
             files_count: 0,
+            deselected: Vec::new(),
             is_focus_run: false,
             is_path_run: false,
         };
@@ -47,11 +57,15 @@ impl GdBenchmarks {
     }
 
     pub fn get_post_init_summary(&self) -> String {
-        format!(
+        let found = format!(
             "   Found {} Rust benchmarks in {} files",
             self.bench_count(),
             self.files_count()
-        )
+        );
+        match self.get_deselection_summary() {
+            Some(deselected) => format!("{found}\n{deselected}"),
+            None => found,
+        }
     }
 
     pub fn get_benchmark(&mut self) -> Option<RustBenchmark> {
@@ -71,8 +85,11 @@ impl GdBenchmarks {
         }
     }
 
-    pub fn finish_setup(&mut self) {
-        self.sort_cases();
+    pub fn finish_setup(&mut self, order: CaseOrder, shuffle_seed: Option<u64>) {
+        self.sort_cases(order);
+        if let Some(seed) = shuffle_seed {
+            self.shuffle_cases(seed);
+        }
         self.files_count = self.get_files_count()
     }
 }
@@ -80,7 +97,10 @@ impl GdBenchmarks {
 pub(crate) struct BenchResult {
     pub outcome: CaseOutcome,
     pub stats: [Duration; METRIC_COUNT],
+    /// Sorted run times of the benchmark, empty if it didn't pass.
+    pub samples: Vec<Duration>,
     pub error: Option<BenchError>,
+    pub comparison: Option<BenchComparison>,
 }
 
 impl BenchResult {
@@ -88,7 +108,9 @@ impl BenchResult {
         Self {
             outcome: CaseOutcome::Skipped,
             stats: [Duration::ZERO, Duration::ZERO],
+            samples: Vec::new(),
             error: None,
+            comparison: None,
         }
     }
 
@@ -96,8 +118,29 @@ impl BenchResult {
         Self {
             outcome: CaseOutcome::Failed,
             stats: [Duration::ZERO, Duration::ZERO],
+            samples: Vec::new(),
             error: Some(err),
+            comparison: None,
+        }
+    }
+
+    pub fn median(&self) -> Duration {
+        self.stats[1]
+    }
+
+    /// Attaches comparison against the baseline, failing the result if the regression is past the fail threshold.
+    pub fn compare(mut self, comparison: BenchComparison) -> Self {
+        if comparison.is_failed() {
+            self.outcome = CaseOutcome::Failed;
+            self.error = Some(BenchError::Regression(comparison));
+        } else {
+            self.comparison = Some(comparison);
         }
+        self
+    }
+
+    pub fn failure_kind(&self) -> Option<FailureKind> {
+        self.error.as_ref().map(BenchError::failure_kind)
     }
 
     pub fn metrics() -> [&'static str; METRIC_COUNT] {
@@ -130,7 +173,9 @@ impl BenchResult {
         BenchResult {
             outcome: CaseOutcome::Passed,
             stats: [min, median],
+            samples: times,
             error: None,
+            comparison: None,
         }
     }
 }
@@ -154,4 +199,14 @@ impl CaseFilterer<RustBenchmark> for GdBenchmarks {
     fn get_cases_mut(&mut self) -> &mut Vec<RustBenchmark> {
         &mut self.benches
     }
+    fn get_deselected(&self) -> &Vec<(&'static str, usize)> {
+        &self.deselected
+    }
+    fn get_deselected_mut(&mut self) -> &mut Vec<(&'static str, usize)> {
+        &mut self.deselected
+    }
+    fn own_name_filters(config: &RunnerConfig) -> Option<&[String]> {
+        let filters = config.bench_filters();
+        (!filters.is_empty()).then_some(filters)
+    }
 }
@@ -5,19 +5,115 @@
 */
 
 use crate::{
-    cases::{rust_test_case::RustTestCase, CaseOutcome},
-    runner::panic::UnwindError,
+    cases::{rust_test_case::RustTestCase, Case, CaseOutcome, FailureKind},
+    runner::{
+        config::{CaseOrder, ConfigError, RunnerConfig},
+        extract_file_subtitle,
+        panic::UnwindError,
+    },
 };
 
 use super::CaseFilterer;
 
 godot::sys::plugin_registry!(pub GD_REHEARSE_RUST_TEST_CASES: RustTestCase);
+godot::sys::plugin_registry!(pub GD_REHEARSE_IGNORED_SUITES: IgnoredSuite);
+godot::sys::plugin_registry!(pub GD_REHEARSE_SUITE_TIMEOUTS: SuiteTimeout);
+
+/// File with tests ignored as a whole, registered with [`ignore_suite!`](crate::ignore_suite).
+#[doc(hidden)]
+#[derive(Clone, Copy, Debug)]
+pub struct IgnoredSuite {
+    pub file: &'static str,
+    pub reason: &'static str,
+}
+
+/// Ignores all `#[gditest]` tests in the file it's invoked in, unless the `--run-ignored` command line argument is provided.
+///
+/// Tests of the ignored suite aren't run, and the suite is listed in the summary with the `reason`. Needs to be invoked at the
+/// module level.
+///
+/// ```no_run
+/// use gd_rehearse::itest::*;
+///
+/// ignore_suite!("broken until the new physics server lands");
+///
+/// #[gditest]
+/// fn collision_is_detected() {
+///     // ...
+/// }
+/// ```
+#[macro_export]
+macro_rules! ignore_suite {
+    ($reason:expr $(,)?) => {
+        ::godot::sys::plugin_add!(GD_REHEARSE_IGNORED_SUITES in gd_rehearse::itest; ::gd_rehearse::itest::IgnoredSuite {
+            file: ::std::file!(),
+            reason: $reason,
+        });
+    };
+}
+
+/// Timeout budget of all tests in a file, registered with [`suite_timeout!`](crate::suite_timeout).
+#[doc(hidden)]
+#[derive(Clone, Copy, Debug)]
+pub struct SuiteTimeout {
+    pub file: &'static str,
+    pub budget: f64,
+}
+
+/// Sets the timeout budget in seconds for all `#[gditest]` tests in the file it's invoked in, taking precedence over the
+/// `--suite-timeout` command line argument. `0` disables the budget.
+///
+/// Once the tests of the suite took longer than the budget in total, its remaining tests are skipped, and the suite is listed
+/// in the summary. Tests can't be interrupted, so the test exceeding the budget still finishes. Needs to be invoked at the module
+/// level.
+///
+/// ```no_run
+/// use gd_rehearse::itest::*;
+///
+/// suite_timeout!(30.0);
+///
+/// #[gditest]
+/// fn navigation_mesh_is_baked() {
+///     // ...
+/// }
+/// ```
+#[macro_export]
+macro_rules! suite_timeout {
+    ($budget:expr $(,)?) => {
+        ::godot::sys::plugin_add!(GD_REHEARSE_SUITE_TIMEOUTS in gd_rehearse::itest; ::gd_rehearse::itest::SuiteTimeout {
+            file: ::std::file!(),
+            budget: $budget,
+        });
+    };
+}
+
+/// Suites marked with [`ignore_suite!`](crate::ignore_suite).
+pub(crate) fn ignored_suites() -> Vec<IgnoredSuite> {
+    __godot_rust_plugin_GD_REHEARSE_IGNORED_SUITES
+        .lock()
+        .expect("can't retrieve IgnoredSuite")
+        .clone()
+}
+
+/// Budgets of the suites declared with [`suite_timeout!`](crate::suite_timeout).
+pub(crate) fn suite_timeouts() -> Vec<SuiteTimeout> {
+    __godot_rust_plugin_GD_REHEARSE_SUITE_TIMEOUTS
+        .lock()
+        .expect("can't retrieve SuiteTimeout")
+        .clone()
+}
 
 #[doc(hidden)]
 pub(crate) struct GdRustItests {
     tests: Vec<RustTestCase>,
+    /// All registered tests, including the filtered out ones.
+    registered: Vec<RustTestCase>,
     files_count: usize,
+    deselected: Vec<(&'static str, usize)>,
+    /// Ignored suites with the counts of their tests which would be run otherwise.
+    ignored_suites: Vec<(IgnoredSuite, usize)>,
     is_focus_run: bool,
+    #[allow(dead_code)]
     is_path_run: bool,
 }
 
@@ -26,6 +122,11 @@ impl GdRustItests {
         self.tests.pop()
     }
 
+    /// Selected tests, in order of execution.
+    pub fn tests(&self) -> impl Iterator<Item = &RustTestCase> {
+        self.tests.iter().rev()
+    }
+
     pub fn tests_count(&self) -> usize {
         self.tests.len()
     }
@@ -37,7 +138,10 @@ impl GdRustItests {
     pub(crate) fn init() -> Self {
         let mut instance = Self {
             tests: Vec::new(),
+            registered: Vec::new(),
             files_count: 0,
+            deselected: Vec::new(),
+            ignored_suites: Vec::new(),
             is_focus_run: false,
             is_path_run: false,
         };
@@ -47,12 +151,62 @@ impl GdRustItests {
         instance
     }
 
+    /// Describes suspicious selection by the keyword: matching all registered tests, which makes it a no-op, or at most one
+    /// of them, which is likely a typo.
+    pub fn keyword_selection_note(&self, config: &RunnerConfig) -> Option<String> {
+        let keyword = config.keyword();
+        if keyword.is_empty() || config.ignore_keywords() {
+            return None;
+        }
+        let total = self.registered.len();
+        let matched = self
+            .registered
+            .iter()
+            .filter(|test| test.keyword == Some(keyword))
+            .count();
+        let hint = match matched {
+            0 | 1 => "it may be misspelled or too narrow",
+            _ if matched == total => "it doesn't narrow the selection",
+            _ => return None,
+        };
+        Some(format!(
+            "keyword '{keyword}' matches {matched} of {total} tests, {hint}"
+        ))
+    }
+
     pub fn get_post_init_summary(&self) -> String {
-        format!(
+        let found = format!(
             "   Found {} Rust tests in {} files",
             self.tests_count(),
             self.files_count()
-        )
+        );
+        let mut summary = match self.get_deselection_summary() {
+            Some(deselected) => format!("{found}\n{deselected}"),
+            None => found,
+        };
+        for (suite, count) in &self.ignored_suites {
+            summary.push_str(&format!(
+                "\n   Suite {}: ignored ({count} tests): {}",
+                extract_file_subtitle(suite.file),
+                suite.reason
+            ));
+        }
+        summary
+    }
+
+    /// Removes tests of the suites marked with [`ignore_suite!`](crate::ignore_suite), unless `run_ignored` is set.
+    pub fn filter_ignored_suites(&mut self, run_ignored: bool) {
+        if run_ignored {
+            return;
+        }
+        for suite in ignored_suites() {
+            let before = self.tests.len();
+            self.tests.retain(|test| test.get_case_file() != suite.file);
+            let count = before - self.tests.len();
+            if count > 0 {
+                self.ignored_suites.push((suite, count));
+            }
+        }
     }
 
     fn get_rust_case() -> Option<RustTestCase> {
@@ -66,17 +220,117 @@ impl GdRustItests {
         while let Some(test) = Self::get_rust_case() {
             self.tests.push(test);
         }
+        self.registered.clone_from(&self.tests);
+    }
+
+    pub fn finish_setup(
+        &mut self,
+        order: CaseOrder,
+        shuffle_seed: Option<u64>,
+        pinned: &[String],
+    ) -> Result<(), ConfigError> {
+        self.sort_cases(order);
+        if let Some(seed) = shuffle_seed {
+            self.shuffle_cases(seed);
+        }
+        self.pin_order(pinned);
+        self.order_dependencies()?;
+        self.files_count = self.get_files_count();
+        Ok(())
+    }
+
+    /// Names from `pinned` which don't match any registered test.
+    pub fn unknown_tests<'a>(&self, pinned: &'a [String]) -> Vec<&'a str> {
+        pinned
+            .iter()
+            .filter(|name| !self.registered.iter().any(|test| test.is_dependency(name)))
+            .map(String::as_str)
+            .collect()
+    }
+
+    /// Moves tests matching `pinned` names to the front of the run, in their order. The rest keeps its order after them.
+    fn pin_order(&mut self, pinned: &[String]) {
+        if pinned.is_empty() {
+            return;
+        }
+        // Tests are popped from the end, so the run order is reversed.
+        let mut rest = self.tests.drain(..).rev().collect::<Vec<_>>();
+        let mut run_order = Vec::with_capacity(rest.len());
+        for name in pinned {
+            while let Some(pos) = rest.iter().position(|test| test.is_dependency(name)) {
+                run_order.push(rest.remove(pos));
+            }
+        }
+        run_order.append(&mut rest);
+        self.tests = run_order.into_iter().rev().collect();
+    }
+
+    /// Reorders tests so every test is executed after its dependencies, keeping the order otherwise.
+    fn order_dependencies(&mut self) -> Result<(), ConfigError> {
+        for test in &self.registered {
+            for dependency in test.depends_on {
+                if !self.registered.iter().any(|t| t.is_dependency(dependency)) {
+                    return Err(ConfigError::new(format!(
+                        "test '{}' depends on unknown test '{}'",
+                        test.full_name(),
+                        dependency
+                    )));
+                }
+            }
+        }
+
+        // Tests are popped from the end, so the run order is reversed.
+        let run_order = self.tests.iter().rev().copied().collect::<Vec<_>>();
+        let mut ordered: Vec<usize> = Vec::with_capacity(run_order.len());
+        let mut visiting: Vec<usize> = Vec::new();
+        for i in 0..run_order.len() {
+            Self::visit_dependencies(i, &run_order, &mut ordered, &mut visiting)?;
+        }
+
+        self.tests = ordered.into_iter().rev().map(|i| run_order[i]).collect();
+        Ok(())
     }
 
-    pub fn finish_setup(&mut self) {
-        self.sort_cases();
-        self.files_count = self.get_files_count()
+    fn visit_dependencies(
+        i: usize,
+        tests: &[RustTestCase],
+        ordered: &mut Vec<usize>,
+        visiting: &mut Vec<usize>,
+    ) -> Result<(), ConfigError> {
+        if ordered.contains(&i) {
+            return Ok(());
+        }
+        if let Some(pos) = visiting.iter().position(|v| *v == i) {
+            let mut cycle = visiting[pos..]
+                .iter()
+                .map(|v| tests[*v].full_name())
+                .collect::<Vec<_>>();
+            cycle.push(tests[i].full_name());
+            return Err(ConfigError::new(format!(
+                "dependency cycle detected: {}",
+                cycle.join(" -> ")
+            )));
+        }
+
+        visiting.push(i);
+        for dependency in tests[i].depends_on {
+            for (j, test) in tests.iter().enumerate() {
+                if test.is_dependency(dependency) {
+                    Self::visit_dependencies(j, tests, ordered, visiting)?;
+                }
+            }
+        }
+        visiting.pop();
+        ordered.push(i);
+        Ok(())
     }
 }
 
 pub(crate) struct TestResult {
     pub(crate) outcome: CaseOutcome,
     pub(crate) error: Option<UnwindError>,
+    /// Set for failures not caused by the test panicking.
+    pub(crate) kind: Option<FailureKind>,
 }
 
 impl TestResult {
@@ -84,6 +338,7 @@ impl TestResult {
         Self {
             outcome: CaseOutcome::Passed,
             error: None,
+            kind: None,
         }
     }
 
@@ -91,6 +346,15 @@ impl TestResult {
         Self {
             outcome: CaseOutcome::Skipped,
             error: None,
+            kind: None,
+        }
+    }
+
+    pub fn skipped_because(reason: String) -> Self {
+        Self {
+            outcome: CaseOutcome::Skipped,
+            error: Some(UnwindError::new(reason)),
+            kind: None,
         }
     }
 
@@ -98,8 +362,29 @@ impl TestResult {
         Self {
             outcome: CaseOutcome::Failed,
             error: Some(err),
+            kind: None,
+        }
+    }
+
+    pub fn timed_out(message: String) -> Self {
+        Self {
+            outcome: CaseOutcome::Failed,
+            error: Some(UnwindError::new(message)),
+            kind: Some(FailureKind::Timeout),
         }
     }
+
+    pub fn failure_kind(&self) -> Option<FailureKind> {
+        if self.outcome != CaseOutcome::Failed {
+            return None;
+        }
+        self.kind
+            .or_else(|| self.error.as_ref().map(FailureKind::from_unwind))
+    }
+
+    pub fn sub_failures(&self) -> &[String] {
+        self.error.as_ref().map_or(&[], |err| err.sub_failures())
+    }
 }
 
 impl CaseFilterer<RustTestCase> for GdRustItests {
@@ -121,4 +406,10 @@ impl CaseFilterer<RustTestCase> for GdRustItests {
     fn get_cases_mut(&mut self) -> &mut Vec<RustTestCase> {
         &mut self.tests
     }
+    fn get_deselected(&self) -> &Vec<(&'static str, usize)> {
+        &self.deselected
+    }
+    fn get_deselected_mut(&mut self) -> &mut Vec<(&'static str, usize)> {
+        &mut self.deselected
+    }
 }
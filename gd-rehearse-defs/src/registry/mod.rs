@@ -6,7 +6,9 @@
 
 use std::collections::HashSet;
 
-use crate::{cases::Case, runner::config::RunnerConfig};
+use crate::cases::Case;
+use crate::runner::config::{CaseOrder, FiltersCombination, RunnerConfig};
+use crate::runner::shuffle::SeededRng;
 
 pub mod bench;
 pub mod itest;
@@ -15,19 +17,47 @@ pub(crate) trait CaseFilterer<T>
 where
     T: Case,
 {
+    #[allow(dead_code)]
     fn is_path_run(&self) -> bool;
+    #[allow(dead_code)]
     fn set_path_run(&mut self, is_path_run: bool);
     fn is_focus_run(&self) -> bool;
     fn set_focus_run(&mut self, is_focus_run: bool);
     fn get_cases(&self) -> &Vec<T>;
     fn get_cases_mut(&mut self) -> &mut Vec<T>;
+    // Counts of deselected cases, by the mechanism which deselected them
+    fn get_deselected(&self) -> &Vec<(&'static str, usize)>;
+    fn get_deselected_mut(&mut self) -> &mut Vec<(&'static str, usize)>;
+
+    // Name filters specific to the kind of cases, replacing the common ones if present
+    fn own_name_filters(_config: &RunnerConfig) -> Option<&[String]> {
+        None
+    }
+
+    // Retain cases, counting the removed ones as deselected by the given mechanism
+    fn retain_counted(&mut self, mechanism: &'static str, keep: impl FnMut(&T) -> bool) {
+        let cases = self.get_cases_mut();
+        let before = cases.len();
+        cases.retain(keep);
+        let removed = before - cases.len();
+        if removed == 0 {
+            return;
+        }
+        let deselected = self.get_deselected_mut();
+        match deselected.iter_mut().find(|(m, _)| *m == mechanism) {
+            Some((_, count)) => *count += removed,
+            None => deselected.push((mechanism, removed)),
+        }
+    }
 
     // Filter on path and keyword
     fn filter_path_keyword(&mut self, config: &RunnerConfig) {
         // Retain only the ones with the specified path and keyword
-        self.get_cases_mut().retain(|t| {
+        self.retain_counted("scene path", |t| {
             t.should_run_scene_path(config.scene_path(), config.only_scene_path())
-                && t.should_run_keyword(config.keyword(), config.ignore_keywords())
+        });
+        self.retain_counted("keyword", |t| {
+            t.should_run_keyword(config.keyword(), config.ignore_keywords())
         });
     }
 
@@ -37,17 +67,90 @@ where
             && (self.is_focus_run() || self.get_cases().iter().any(|t| t.is_case_focus()))
     }
 
-    // Filter on focus and filters
+    // Filter on focus, priority and filters
     fn filter_focus_filters(&mut self, config: &RunnerConfig) {
         let is_focus_run = self.check_focus_run(config) || self.is_focus_run();
         self.set_focus_run(is_focus_run);
-        self.get_cases_mut()
-            .retain(|c| c.should_run_focus(is_focus_run) && c.should_run_filters(config.filters()))
+        // Every case is counted only for the first mechanism which deselected it
+        self.retain_counted("focus", |c| c.should_run_focus(is_focus_run));
+        self.retain_counted("smoke", |c| c.should_run_smoke(config.smoke()));
+        self.retain_counted("priority", |c| {
+            c.should_run_priority(config.priority_threshold())
+        });
+        self.retain_counted("suite", |c| c.should_run_suites(config.suites()));
+        self.retain_counted("filter", |c| Self::should_run_all_filters(c, config));
+        self.retain_counted("exclude", |c| !Self::is_excluded(c, config));
+        self.retain_counted("shard", |c| c.should_run_shard(config.shard()));
+    }
+
+    // Describe how many cases were deselected and by which mechanism, if any were
+    fn get_deselection_summary(&self) -> Option<String> {
+        let deselected = self.get_deselected();
+        if deselected.is_empty() {
+            return None;
+        }
+        let total = deselected.iter().map(|(_, count)| count).sum::<usize>();
+        let breakdown = deselected
+            .iter()
+            .map(|(mechanism, count)| format!("{count} by {mechanism}"))
+            .collect::<Vec<_>>();
+        Some(format!("   Deselected {total}: {}", breakdown.join(", ")))
+    }
+
+    // Check name and description filters, combined according to config
+    fn should_run_all_filters(case: &T, config: &RunnerConfig) -> bool {
+        let by_name = Self::should_run_name_filters(case, config);
+        let by_description = case.should_run_description_filters(config.description_filters());
+
+        let name_present = Self::own_name_filters(config).is_some()
+            || !config.filters().is_empty()
+            || !config.filters_glob().is_empty();
+        let both_present = name_present && !config.description_filters().is_empty();
+        if both_present && config.description_filters_mode() == FiltersCombination::Or {
+            return by_name || by_description;
+        }
+        by_name && by_description
+    }
+
+    // Check substring and glob name filters - case is retained if it matches any of them
+    fn should_run_name_filters(case: &T, config: &RunnerConfig) -> bool {
+        if let Some(filters) = Self::own_name_filters(config) {
+            return case.should_run_filters(filters, FiltersCombination::Or);
+        }
+        match (
+            config.filters().is_empty(),
+            config.filters_glob().is_empty(),
+        ) {
+            (_, true) => case.should_run_filters(config.filters(), config.filters_mode()),
+            (true, false) => case.should_run_glob_filters(config.filters_glob()),
+            (false, false) => {
+                case.should_run_filters(config.filters(), config.filters_mode())
+                    || case.should_run_glob_filters(config.filters_glob())
+            }
+        }
+    }
+
+    // Check exclusion globs, applied after all inclusion filters
+    fn is_excluded(case: &T, config: &RunnerConfig) -> bool {
+        config
+            .exclude_glob()
+            .iter()
+            .any(|pattern| pattern.matches(case.get_case_name()))
+    }
+
+    // Sort in deterministic order, or keep the registration one with only priorities applied
+    fn sort_cases(&mut self, order: CaseOrder) {
+        match order {
+            CaseOrder::Sorted => self.get_cases_mut().sort_by(|a, b| Case::order(a, b)),
+            CaseOrder::Defined => self.get_cases_mut().sort_by_key(|c| c.get_case_priority()),
+        }
     }
 
-    // Sort in deterministic order
-    fn sort_cases(&mut self) {
-        self.get_cases_mut().sort_by(|a, b| Case::order(a, b))
+    // Shuffle with the given seed, still executing the cases with higher priority first
+    fn shuffle_cases(&mut self, seed: u64) {
+        let cases = self.get_cases_mut();
+        SeededRng::new(seed).shuffle(cases);
+        cases.sort_by_key(|c| c.get_case_priority());
     }
 
     // Get files
@@ -59,6 +162,7 @@ where
         set.len()
     }
 
+    #[allow(dead_code)]
     fn is_any_path_eq(&self, path: &str) -> bool {
         self.get_cases().iter().any(|c| c.scene_path_eq(path))
     }
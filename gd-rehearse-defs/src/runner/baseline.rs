@@ -0,0 +1,139 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::time::Duration;
+
+use crate::cases::Case;
+
+use super::config::{ConfigError, ConfigErrorKind};
+use super::{extract_file_subtitle, format_percent_delta};
+
+/// Median run times of benchmarks from previous run, to compare the current results against.
+///
+/// Stored in a simple text file with one `file.rs::benchmark_name median_in_nanoseconds` entry per line.
+#[derive(Default)]
+pub(crate) struct BenchBaseline {
+    medians: HashMap<String, Duration>,
+}
+
+impl BenchBaseline {
+    pub fn key(bench: &impl Case) -> String {
+        format!(
+            "{}::{}",
+            extract_file_subtitle(bench.get_case_file()),
+            bench.get_case_name()
+        )
+    }
+
+    pub fn load(path: &str) -> Result<Self, ConfigError> {
+        let content = std::fs::read_to_string(path).map_err(|err| {
+            ConfigError::of_kind(
+                ConfigErrorKind::File,
+                &[],
+                format!("couldn't read benchmark baseline '{path}': {err}"),
+            )
+        })?;
+
+        let mut medians = HashMap::new();
+        for (i, line) in content.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let parsed = line
+                .rsplit_once(' ')
+                .and_then(|(key, nanos)| Some((key.trim(), nanos.parse::<u64>().ok()?)));
+            let Some((key, nanos)) = parsed else {
+                return Err(ConfigError::of_kind(
+                    ConfigErrorKind::File,
+                    &[],
+                    format!(
+                        "malformed benchmark baseline '{path}' at line {}: '{line}'",
+                        i + 1
+                    ),
+                ));
+            };
+            medians.insert(key.to_owned(), Duration::from_nanos(nanos));
+        }
+
+        Ok(Self { medians })
+    }
+
+    pub fn save(path: &str, medians: &[(String, Duration)]) -> std::io::Result<()> {
+        let mut content = String::new();
+        for (key, median) in medians {
+            content.push_str(&format!("{key} {}\n", median.as_nanos()));
+        }
+        std::fs::write(path, content)
+    }
+
+    pub fn get(&self, key: &str) -> Option<Duration> {
+        self.medians.get(key).copied()
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum ComparisonLevel {
+    Ok,
+    Warn,
+    Fail,
+}
+
+/// Comparison of benchmark median against its baseline.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct BenchComparison {
+    pub baseline: Duration,
+    pub current: Duration,
+    /// Positive for regressions, negative for improvements.
+    pub delta_pct: f64,
+    pub level: ComparisonLevel,
+    pub fail_pct: Option<f64>,
+}
+
+impl BenchComparison {
+    pub fn new(
+        baseline: Duration,
+        current: Duration,
+        warn_pct: Option<f64>,
+        fail_pct: Option<f64>,
+    ) -> Self {
+        let baseline_nanos = baseline.as_nanos().max(1) as f64;
+        let delta_pct = (current.as_nanos() as f64 - baseline_nanos) / baseline_nanos * 100.;
+
+        let level = if fail_pct.is_some_and(|fail| delta_pct > fail) {
+            ComparisonLevel::Fail
+        } else if warn_pct.is_some_and(|warn| delta_pct > warn) {
+            ComparisonLevel::Warn
+        } else {
+            ComparisonLevel::Ok
+        };
+
+        Self {
+            baseline,
+            current,
+            delta_pct,
+            level,
+            fail_pct,
+        }
+    }
+
+    pub fn is_failed(&self) -> bool {
+        self.level == ComparisonLevel::Fail
+    }
+}
+
+impl Display for BenchComparison {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let label = match self.level {
+            ComparisonLevel::Ok => "ok",
+            ComparisonLevel::Warn => "warn",
+            ComparisonLevel::Fail => "FAIL",
+        };
+        write!(f, "{label:>6} {:>9}", format_percent_delta(self.delta_pct))
+    }
+}
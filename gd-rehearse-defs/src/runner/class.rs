@@ -4,30 +4,62 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
 */
 
+// Raised from code generated by `#[derive(GodotClass)]` and `#[godot_api]`.
+#![allow(clippy::result_large_err)]
+
 use godot::obj::WithBaseField;
-use godot::prelude::{godot_api, Base, GString, GodotClass, INode, Node, PackedStringArray};
+use godot::prelude::{
+    godot_api, Base, Dictionary, GString, GodotClass, INode, Node, PackedStringArray,
+};
 
+use crate::assertions::resource::set_update_snapshots;
 use crate::cases::rust_bench::{BenchContext, BenchError, RustBenchmark};
-use crate::cases::rust_test_case::{RustTestCase, TestContext};
-use crate::cases::{Case, CaseOutcome, CaseType};
+use crate::cases::rust_test_case::{set_require_services, RustTestCase, TestContext};
+use crate::cases::{
+    set_display_name_hook, Case, CaseOutcome, CaseType, DisplayNameHook, FailureKind,
+};
 
 use crate::registry::bench::{BenchResult, GdBenchmarks};
-use crate::registry::itest::{GdRustItests, TestResult};
+use crate::registry::itest::{
+    ignored_suites, suite_timeouts, GdRustItests, SuiteTimeout, TestResult,
+};
 use crate::registry::CaseFilterer;
 
-use super::config::RunnerConfig;
-use super::extract_file_subtitle;
-use super::panic::UnwindError;
+use super::baseline::{BenchBaseline, BenchComparison};
+use super::config::{
+    CliConfig, ConfigError, ConfigErrorKind, CustomArg, GroupBy, RunnerConfig, TimeUnit,
+};
+use super::interactive::{browse_failures, is_interactive_terminal};
+use super::interrupt::{install_handler, is_interrupted, restore_handler, INTERRUPTED_EXIT_CODE};
+use super::panic::{handle_panic, set_panic_detail, UnwindError};
 use super::print::MessageWriter;
-
+use super::report::{
+    reporters, CaseListing, CaseReport, HumanReporter, ReportCaseKind, ReportDelta, ReportFormat,
+    ReportTarget, Reporter, RunReport, StatusReporter,
+};
+use super::shuffle::case_seed;
+use super::watchdog::Watchdog;
+use super::{
+    extract_file_subtitle, format_percent, is_headless_run, set_percent_precision,
+    set_relative_paths_root,
+};
+
+use std::collections::HashMap;
+use std::io::Write;
 use std::time::{Duration, Instant};
 
-#[derive(Copy, Clone, Debug, Default)]
+#[derive(Clone, Debug, Default)]
 pub(crate) struct RunnerSummary {
     kind: CaseType,
     total: i64,
     passed: i64,
     skipped: i64,
+    failure_kinds: Vec<FailureKind>,
+    passed_on_retry: Vec<String>,
+    /// Passed tests which took longer than `--timeout-warn`.
+    slow_warnings: Vec<String>,
+    /// Suites over their timeout budget, with the budget and the count of their skipped tests.
+    suite_aborts: Vec<(String, f64, usize)>,
 }
 
 impl RunnerSummary {
@@ -50,28 +82,103 @@ impl RunnerSummary {
         self.skipped += 1;
     }
 
-    fn update_stats(&mut self, test: &impl Case, outcome: &CaseOutcome, failed: &mut Vec<String>) {
+    fn update_stats(
+        &mut self,
+        test: &impl Case,
+        outcome: &CaseOutcome,
+        failure_kind: Option<FailureKind>,
+        sub_failures: &[String],
+        failed: &mut Vec<String>,
+    ) {
         self.inc_total();
         match outcome {
             CaseOutcome::Passed => self.inc_passed(),
-            CaseOutcome::Failed => failed.push(format!(
-                "{} > {}",
-                extract_file_subtitle(test.get_case_file()),
-                test.get_case_name()
-            )),
+            CaseOutcome::Failed => {
+                let kind = failure_kind.unwrap_or(FailureKind::Panic);
+                self.failure_kinds.push(kind);
+                let mut entry = format!(
+                    "{} > {} [{kind}]",
+                    extract_file_subtitle(test.get_case_file()),
+                    test.get_case_display_name()
+                );
+                for sub_failure in sub_failures {
+                    entry.push_str(&format!("\n      - {sub_failure}"));
+                }
+                failed.push(entry)
+            }
             CaseOutcome::Skipped => self.inc_skipped(),
         }
     }
 
-    fn conclude(&self, run_time: Duration, failed_list: &mut Vec<String>) -> bool {
+    fn add_passed_on_retry(&mut self, test: &impl Case, attempt: u32, retries: u32) {
+        self.passed_on_retry.push(format!(
+            "{} > {} (attempt {} of {}, retries: {retries})",
+            extract_file_subtitle(test.get_case_file()),
+            test.get_case_display_name(),
+            attempt + 1,
+            retries + 1
+        ));
+    }
+
+    fn add_slow_warning(&mut self, test: &impl Case, duration: Duration, warn_timeout: f64) {
+        self.slow_warnings.push(format!(
+            "{} > {} (took {:.3}s, warn timeout: {warn_timeout}s)",
+            extract_file_subtitle(test.get_case_file()),
+            test.get_case_display_name(),
+            duration.as_secs_f64()
+        ));
+    }
+
+    fn add_suite_abort(&mut self, test: &impl Case, budget: f64) {
+        let file = extract_file_subtitle(test.get_case_file());
+        match self
+            .suite_aborts
+            .iter_mut()
+            .find(|(suite, _, _)| suite == file)
+        {
+            Some((_, _, skipped)) => *skipped += 1,
+            None => self.suite_aborts.push((file.to_owned(), budget, 1)),
+        }
+    }
+
+    /// Breakdown of failures by their kind, eg. `3 assertion failures, 1 panic`.
+    fn failure_breakdown(&self) -> String {
+        let mut kinds = self.failure_kinds.clone();
+        kinds.sort();
+        let mut breakdown = Vec::new();
+        for chunk in kinds.chunk_by(|a, b| a == b) {
+            breakdown.push(chunk[0].for_summary(chunk.len()));
+        }
+        breakdown.join(", ")
+    }
+
+    /// Percentage of passed cases among the executed ones. If no case was executed, nothing failed either, so it's `100`.
+    fn pass_rate(&self) -> f64 {
+        let executed = self.total - self.skipped;
+        if executed == 0 {
+            return 100.;
+        }
+        self.passed as f64 / executed as f64 * 100.
+    }
+
+    fn conclude(
+        &self,
+        run_time: Duration,
+        failed_list: &mut Vec<String>,
+        min_pass_rate: Option<f64>,
+        group_by: GroupBy,
+        cases: &[CaseReport],
+        writer: &MessageWriter,
+    ) -> bool {
         let Self {
             kind,
             total,
             passed,
             skipped,
+            ..
         } = *self;
 
-        let writer = MessageWriter::new(false);
+        let writer = writer.loud();
 
         let kind_display = kind.for_summary();
 
@@ -84,9 +191,12 @@ impl RunnerSummary {
         let failed = total - passed - skipped;
         let all_passed = failed == 0;
 
-        let outcome = CaseOutcome::from_bool(all_passed);
+        let pass_rate = self.pass_rate();
+        let success = all_passed || min_pass_rate.is_some_and(|min| pass_rate >= min);
+
+        let outcome = CaseOutcome::from_bool(success);
 
-        let run_time = (run_time.as_secs_f32() * 100.).round() / 100.;
+        let run_time = writer.format_duration(run_time, TimeUnit::Seconds);
 
         let extra = if skipped > 0 {
             format!(", {skipped} skipped")
@@ -95,25 +205,93 @@ impl RunnerSummary {
         };
 
         writer.println(&format!(
-            "{kind_display} result: {outcome} {passed} passed; {failed} failed{extra}. Elapsed: {run_time:.2}s."
+            "{kind_display} result: {outcome} {passed} passed; {failed} failed{extra}. Elapsed: {run_time}."
         ));
 
-        if !all_passed {
-            writer.println("\n  Failed:");
-            let max = 10;
-            for test in failed_list.iter().take(max) {
+        if let Some(min_pass_rate) = min_pass_rate {
+            writer.println(&format!(
+                "Pass rate: {} (required: {}).",
+                format_percent(pass_rate),
+                format_percent(min_pass_rate)
+            ));
+        }
+
+        if !self.passed_on_retry.is_empty() {
+            writer.println("\n  Passed on retry:");
+            for test in &self.passed_on_retry {
+                writer.println(&format!("  * {test}"));
+            }
+        }
+
+        if !self.suite_aborts.is_empty() {
+            writer.println("\n  Suites over their timeout budget:");
+            for (suite, budget, skipped) in &self.suite_aborts {
+                writer.println(&format!(
+                    "  * {suite}: exceeded {budget}s, {skipped} remaining tests skipped"
+                ));
+            }
+        }
+
+        if !self.slow_warnings.is_empty() {
+            writer.println(&format!(
+                "\n  Warning: exceeded {}:",
+                CliConfig::CMD_USER_TIMEOUT_WARN
+            ));
+            for test in &self.slow_warnings {
                 writer.println(&format!("  * {test}"));
             }
+        }
+
+        if group_by != GroupBy::Order {
+            let kind = ReportCaseKind::from(kind);
+            let cases = cases
+                .iter()
+                .filter(|case| case.kind == kind)
+                .collect::<Vec<_>>();
+            writer.print_grouped_cases(&cases, group_by);
+        }
 
-            if failed_list.len() > max {
-                writer.println(&format!("  * ... and {} more.", failed_list.len() - max));
+        if !all_passed {
+            writer.println(&format!("\n  Failed: {}", self.failure_breakdown()));
+            let max = 10;
+            if group_by == GroupBy::Order {
+                for test in failed_list.iter().take(max) {
+                    writer.println(&format!("  * {test}"));
+                }
+
+                if failed_list.len() > max {
+                    writer.println(&format!("  * ... and {} more.", failed_list.len() - max));
+                }
             }
 
             failed_list.clear();
 
             writer.println("\n");
         }
-        all_passed
+        success
+    }
+}
+
+/// Reason of the run ending, printed in the `--exit-marker` footer.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum ExitReason {
+    AllPassed,
+    MinPassRateMet,
+    TestFailures,
+    ConfigError,
+    Interrupted,
+}
+
+impl std::fmt::Display for ExitReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let reason = match self {
+            ExitReason::AllPassed => "all_passed",
+            ExitReason::MinPassRateMet => "min_pass_rate_met",
+            ExitReason::TestFailures => "test_failures",
+            ExitReason::ConfigError => "config_error",
+            ExitReason::Interrupted => "interrupted",
+        };
+        f.write_str(reason)
     }
 }
 
@@ -127,15 +305,15 @@ impl RunnerSummary {
 ///
 /// - `run_tests`: If set, functions annotated with `#[gditest]` will be executed. Defaults to `true`.
 /// - `run_benchmarks`: If set, functions annotated with `#[gdbench]` will be executed. Defaults to `true`. If `run_tests` is also `true`, benchmarks
-///    will only be executed if all tests pass successfully.
+///   will only be executed if all tests pass successfully.
 /// - `test_keyword`: If set, only tests and benchmarks with the same `keyword` specified will be executed. Defaults to an empty string, meaning
-///    that only tests and benchmarks without a `keyword` set will be executed. It takes precedence over `focus` and `filters`—they will be
-///    assessed, but only in the context of this `keyword`.
+///   that only tests and benchmarks without a `keyword` set will be executed. It takes precedence over `focus` and `filters`—they will be
+///   assessed, but only in the context of this `keyword`.
 /// - `ignore_keywords`: If set, all tests and benchmarks will be executed regardless of their set `keyword`.
 /// - `disallow_focus`: If set, the `focus` attribute of tests and benchmarks will be ignored.
 /// - `disallow_skip`: If set, the `skip` attribute of tests and benchmarks will be ignored.
 /// - `test_filters`: An array of strings tested against the names of tests and benchmarks. Those with names containing at least one of the specified
-///    filters will be executed.
+///   filters will be executed.
 /// - `only_scene_path`: If `true`, runner will execute only tests for its scene path specified in their `scene_path` attribute.
 ///
 /// ## Command Line Arguments
@@ -149,11 +327,205 @@ impl RunnerSummary {
 /// - `optional arguments`: Options such as:
 ///   - `--rust-test` or `--rust-benchmarks`: If at least one is selected, overwrites the analogous properties and runs only the specified element.
 ///   - `--disallow-focus` or `--allow-focus`: Overwrites the `disallow_focus` property.
+///   - `--forbid-focus`: Fails the run before executing anything if any test or benchmark is focused, listing the focused ones.
+///     Unlike `--disallow-focus`, which just ignores focus, it guards against focused cases being committed.
+///   - `--fail-empty`: Fails the run if no tests or benchmarks are registered at all, which usually means a broken build. Cases
+///     excluded by filters still count as registered. When it or `--forbid-focus` fails the run, reports are still written, with
+///     the reason the run was aborted, and `--exit-marker` reports `config_error`.
 ///   - `--disallow-skip` or `--disallow-focus`: Overwrites the `disallow_skip` property.
+///   - `--require-services`: Fails tests calling `require_service` with an unreachable service instead of skipping them. Meant
+///     for CI, where the services are guaranteed to be up. `--disallow-skip` fails them as well.
+///   - `--update-snapshots`: Saves the resources checked by `assert_resource_matches!` to the files they're compared against,
+///     instead of comparing them. Meant to regenerate the committed golden files after an intended change.
 ///   - `--mute-keyword` or `--keyword=my_keyword`: Either mutes the `test_keyword` property or replaces it with the specified one.
 ///   - `--ignore-keywords`: Replaces the `ignore_keywords` property.
 ///   - `--mute-filters` or `--filters=[filter1,filter2]`: Either mutes the `test_filters` property or replaces it with the specified filters.
+///     Like all other name filters, it matches the canonical function name of the case, never its `display_name`.
+///   - `--bench-filter=[filter1,filter2]`: Executes only benchmarks with names containing any of the specified filters. Replaces
+///     `--filters`, `--filters-glob` and `test_filters` for benchmarks, leaving them only for tests.
+///   - `--filters-mode=any|all`: Either requires test and benchmark names to contain any of the name filters (`any`, default) or
+///     all of them (`all`). Requires name filters to be specified.
+///   - `--group-by=order|outcome|suite`: Organizes the case details at the end of the run. `order` (default) lists only the failures
+///     in execution order, `outcome` lists all cases clustered into failed, skipped and passed ones, and `suite` lists all cases
+///     clustered by the file they are defined in.
+///   - `--filters-glob=[pattern1,pattern2]`: Executes only tests and benchmarks with names matching at least one of the shell-style
+///     glob patterns, supporting `*`, `?`, `[abc]` and `\` escapes, eg. `serialize_*_roundtrip`. Cases matching `--filters` are
+///     also retained.
+///   - `--suite=name`: Executes only tests and benchmarks from the suite, which is the file they are defined in, eg. `itest.rs` or
+///     `itest`. Can be specified multiple times to run several suites. Unknown suite names are reported as a config error.
+///   - `--exclude-glob=[pattern1,pattern2]`: Drops tests and benchmarks with names matching any of the glob patterns, eg. `*_wip`.
+///     Applied after all inclusion filters.
 ///   - `--only-scene-path`: Sets `only_scene_path` property with `true`
+///   - `--filter-desc=[filter1,filter2]`: Executes only tests and benchmarks with descriptions containing at least one of the
+///     specified filters. If used together with name filters, both need to match.
+///   - `--filter-desc-mode=and|or`: Either requires both name and description filters to match (`and`, default), or any of them (`or`).
+///   - `--priority-threshold=1`: Executes only tests and benchmarks with `priority` equal or higher than the specified one.
+///   - `--min-pass-rate=95`: Considers the run successful if at least the given percentage of executed tests and benchmarks
+///     passed. All failures are still reported.
+///   - `--summary-file=path/to/file.txt`: Mirrors the runner output to the specified file. The file is always rendered 80 columns
+///     wide, while the console output adjusts to the `COLUMNS` environment variable, if set.
+///   - `--strict-cli`: Rejects ambiguous argument combinations, in which one of the arguments is meaningless, eg. `--ignore-keywords`
+///     together with `--keyword=my_keyword`.
+///   - `--bench-save-baseline=path/to/file.txt`: Saves the benchmark medians to the specified file, for later comparison.
+///   - `--bench-baseline=path/to/file.txt`: Compares the benchmark medians to the ones saved in the specified file.
+///   - `--compare-report=path/to/report.json`: Compares the outcomes to the JSON report of a previous run, printing newly failing
+///     and newly passing cases, as well as the ones present in only one of the reports.
+///   - `--perf-regression-pct=50`: Together with `--compare-report`, warns about tests passing in both runs which got slower than
+///     in the previous report by more than the given percentage, showing their old and new durations. Tests which ran for less
+///     than a millisecond previously are skipped, as their durations are too noisy.
+///   - `--render-report=path/to/report.json`: Doesn't run any case. Instead loads the JSON report of a previous run and passes it
+///     through the console output and all reporters selected with `--format`, `--json` and `--junit`, eg. to convert it to JUnit XML.
+///     Exits with the outcome saved in the report. Reports with incompatible schema version are rejected.
+///   - `--merge-reports=shard-1.json,shard-2.json` or `--merge-reports=reports/shard-*.json`: Like `--render-report`, but merges the
+///     JSON reports of sharded runs into one, with the cases of all of them. Glob patterns are allowed only in the file names. The
+///     merged run fails if any shard failed, and lasts as long as the longest shard. Cases present in multiple reports, which
+///     shouldn't happen with `--shard`, are warned about.
+///   - `--bench-group-relative`: After the benchmark results, prints a table for every benchmark `group`, with medians relative to
+///     the fastest benchmark in the group, shown as `1.00x`.
+///   - `--bench-histogram`: Prints histogram of run times below the results of every passed benchmark, revealing multimodal
+///     distributions hidden by the summary metrics.
+///   - `--bench-warn-pct=10` and `--bench-fail-pct=25`: Regression thresholds for `--bench-baseline` comparison. Benchmarks slower than
+///     the baseline by more than the warn threshold are labelled with `warn`, and the ones slower by more than the fail threshold fail.
+///   - `--format=json,junit`: Prints the report of the whole run in the specified formats to the standard output after the run.
+///     With any of them, the human-readable output is printed to the standard error instead, so the standard output can be parsed.
+///     `dots` format instead replaces the line printed for every test with a single character (`.` passed, `F` failed,
+///     `S` skipped), with details of failed tests printed after all tests. With `json`, errors in the configuration are also
+///     printed to the standard output as a single line `{"type": "config_error", "errors": [...]}` object, with the `kind`,
+///     `message` and offending `args` of every error, before exiting with the config error code.
+///   - `--json=path/to/report.json` and `--junit=path/to/report.xml`: Writes the JSON or JUnit XML report of the whole run to the
+///     specified file. All report arguments, together with `--summary-file`, can be combined to emit every format from a single run.
+///     Both reports include the `schema_version` of their format in `major.minor` form, bumped in major version on breaking changes,
+///     and the `runner_version` of gd-rehearse which produced them.
+///     JUnit `<testcase>` and `<testsuite>` elements carry `time` in seconds with microsecond precision, measured by the same
+///     clock as the console output, and every `<testsuite>` carries the UTC `timestamp` of the run start.
+///   - `--csv=path/to/report.csv`: Writes one row per case to the specified CSV file, with `name`, `suite`, `outcome`,
+///     `duration_ms`, `failure_kind` and `message` columns. Fields are quoted according to RFC 4180.
+///   - `--status-file=path/to/status.json`: Writes just the outcome and counts of the run, e.g.
+///     `{"status":"passing","passed":120,"failed":0,"skipped":2}`, to feed badge generators. Written for failed runs too,
+///     including the ones ending before any case was run, e.g. on invalid arguments.
+///   - `--artifacts-dir=path/to/dir`: Creates the directory before the run and writes all produced artifacts under it: relative
+///     paths passed to `--summary-file`, `--bench-save-baseline`, `--json`, `--junit`, `--csv` and `--status-file` are resolved
+///     against it.
+///     Human-readable console output is still emitted, unless `--quiet-run` is specified.
+///   - `--timeout=10`: Fails tests which took longer than the specified number of seconds. Tests can't be interrupted, so the timeout
+///     is checked after the test finishes. Tests declaring their own `timeout` use it instead, with `0` disabling the timeout.
+///     The failure message includes the last checkpoint recorded with `TestContext::checkpoint`.
+///     The timeout doesn't apply to benchmarks, which run many iterations and can take much longer.
+///   - `--suite-timeout=60`: Budget in seconds for all tests of every suite, which is the file they are defined in. Once the tests
+///     of a suite took longer in total, its remaining tests are skipped and the suite is listed in the summary. Suites declaring
+///     their own budget with [`suite_timeout!`](crate::suite_timeout) use it instead, with `0` disabling the budget.
+///   - `--timeout-warn=5`: Soft budget of tests in seconds. Tests passing, but taking longer than that, are listed as warnings in the
+///     summary without failing the run, so the budget can be tightened before `--timeout` is. It needs to be at most `--timeout`.
+///   - `--bench-timeout=60`: Fails benchmarks which took longer than the specified number of seconds, including their setup and
+///     cleanup. Checked after the benchmark finishes, as with `--timeout`.
+///   - `--interactive`: After the run, lists the failed tests and benchmarks by number and prints full details of the ones selected.
+///     Has effect only if both standard input and output are terminals.
+///   - `--shard=2/4`: Splits the selected tests and benchmarks into the specified count of shards, running only the one with given
+///     index, starting at 1. Cases are assigned to shards by the hash of their file and name, so the same case always lands in
+///     the same shard. Useful for distributing the run across multiple CI machines. Tests depending on cases from other shards
+///     are skipped.
+///   - `--order=sorted|defined`: Order of execution when the run isn't shuffled. `sorted` (default) executes the cases sorted by
+///     the path of the file they are defined in, then by their line and name, so the order is the same for every build. `defined`
+///     executes them in the order of registration, which can change with the link order. In both, cases with higher priority
+///     are executed first. Can't be combined with `--shuffle`.
+///   - `--order-file=path/to/order.txt`: Executes the tests listed in the file first, in the listed order, and the remaining ones
+///     after them in the order they would be executed otherwise. Tests are listed one per line as `test_name` or
+///     `file.rs::test_name`, with empty lines and lines starting with `#` ignored. Listed tests which don't exist are reported
+///     with a warning. Dependencies of the tests are still executed before them. Useful for reproducing a failing order found
+///     with `--shuffle`, even after the set of tests changed.
+///   - `--shuffle`: Executes tests and benchmarks in random order, still respecting their priorities and dependencies.
+///   - `--seed=12345`: Seed of the run, used for `--shuffle` and exposed to tests through `TestContext::seed` and
+///     `TestContext::rng`. If not provided, it's read from the `GODOT_TEST_SEED` environment variable, or generated randomly.
+///     The used seed is always printed at the start of the run, so both the order and test data can be reproduced.
+///   - `--stall-timeout=60`: If no test or benchmark finishes within the specified number of seconds, prints the currently running
+///     one with its elapsed time to the standard error, repeating after every further interval. It's only a diagnostic, the case
+///     isn't interrupted.
+///   - `--retries=2`: Retries failed tests up to the specified number of times, unless they declare their own `retries`. Tests which
+///     passed on retry are listed in the summary.
+///   - `--retry-on=timeout,panic`: Retries only failures of the listed kinds, failing the test immediately on other ones. Kinds
+///     are `assertion`, `panic`, `setup`, `cleanup`, `leak`, `regression` and `timeout`. All kinds are retried by default.
+///     Failures of the crate's assertion macros, soft assertions, `assert_eq!`, `assert_ne!` and `assert!` without a custom message
+///     are assertions. `assert!` with a custom message can't be told apart from `panic!`, so it counts as a panic.
+///   - `--sort-output`: Sorts the filters, suites, metadata and overrides listed at the start of the run, so the output is stable
+///     regardless of the order they were provided in.
+///   - `--smoke`: Executes only the tests marked with `smoke` attribute, for a quick check before the full run. Benchmarks aren't
+///     executed. The run information states that it's a smoke run.
+///   - `--strict-selection`: Fails the run if the keyword matches all the tests or at most one of them, instead of only printing
+///     a note about it. Such selections are likely caused by a no-op keyword, a typo or renamed tests.
+///   - `--fail-on-mode-skip`: Fails tests which would be skipped because they require different mode of the run than the current
+///     one (see `requires` attribute of `#[gditest]`). Useful in CI, where the run mode is supposed to be guaranteed.
+///   - `--only-leaf-failures`: Prints only the failed tests, each as a single entry with its file, name and failure message,
+///     hiding passed and skipped tests. Summaries are printed as usual. Can't be combined with `--format=dots`.
+///   - `--no-signal-handler`: Doesn't install the `SIGINT` handler. By default, interrupting the run (e.g. with Ctrl+C) stops it
+///     after the currently running case, printing the summary of finished cases with the count of the ones that didn't run, and
+///     exits with code 130. Useful when the signals are handled by the embedding application.
+///   - `--exit-marker`: Prints machine-readable footer line to the standard error at the end of the run, eg. `GODOT_TEST_EXIT: failures=2 reason=test_failures`.
+///     The reason is one of `all_passed`, `min_pass_rate_met`, `test_failures`, `config_error` or `interrupted`, the latter
+///     coming with exit code 130. `config_error` is also the reason of runs aborted before any case was executed, by invalid
+///     configuration, a panicking [`before_run`](GdTestRunner::set_before_run) hook, `--forbid-focus` or `--fail-empty`.
+///   - `--banner-fill==`: Pads the centered run information printed at the start of the run with the specified character instead
+///     of spaces.
+///   - `--no-banner`: Omits the banner and run information printed at the start of the run. Results and the summary are still printed.
+///   - `--max-message-len=1000`: Truncates failure messages printed to the console to the specified number of characters. `0`,
+///     the default, means no truncation. Reports requested with `--json`, `--junit` or `--format` always contain full messages.
+///   - `--percent-precision=2`: Number of decimal places of percentages in the output: the pass rate printed with
+///     `--min-pass-rate` and benchmark differences against `--bench-baseline`. Defaults to `2`.
+///   - `--ignore-unknown-args`: Prints a warning listing unrecognized arguments instead of failing the run because of them. Useful
+///     when the same arguments are passed to runners of different versions.
+///   - Arguments declared with [`GdTestRunner::add_custom_arg`] are recognized as well, with their values passed to tests.
+///   - `--list-json`: Prints the selected tests and benchmarks as JSON array of objects with `kind`, `name`, `suite`, `tags`, `file`,
+///     `line` and `description` fields, and exits without running them. All filters are honored.
+///   - `--run-ignored`: Runs also the tests from suites marked with [`ignore_suite!`](crate::ignore_suite), which are otherwise
+///     only listed in the summary with the reason.
+///   - `--panic-detail=minimal|message|backtrace`: Controls how much of a caught panic is recorded in the failure: only that the
+///     case `panicked`, the panic `message` (default), or the message followed by the `backtrace`. The backtrace is captured only
+///     if enabled with `RUST_BACKTRACE` environment variable.
+///   - `--no-run`: Selects the cases as usual and prints the summaries followed by the selected cases in execution order, but
+///     doesn't run them or the run hooks. It's the base of all modes which don't run any case:
+///     - `--list-json` and `--list-suites` imply it and only change the output, so they can be combined with it.
+///     - `--check-config`, `--render-report`, `--merge-reports` and `--describe` stop before any selection, so they can't be
+///       combined with it.
+///     - Combining any two of `--list-json`, `--list-suites`, `--check-config`, `--render-report`, `--merge-reports` and
+///       `--describe` is a config error.
+///   - `--max-total-duration-fail=600`: Budget of the whole run in seconds. The run isn't stopped when it's exceeded, but once all
+///     cases finish, it's marked as failed. The summary reports the budget and the actual total duration.
+///   - `--time-unit=auto|s|ms|us|ns`: Unit of the durations in the human-readable summaries, like the elapsed time of the run
+///     and the `--perf-regression-pct` warnings. `auto` picks the largest unit in which the value is at least one. Without it,
+///     every place keeps its default unit. Reports, like JSON, always store durations in nanoseconds. Benchmark tables keep
+///     microseconds, so their columns stay aligned.
+///   - `--relative-paths` or `--relative-paths=path/to/root`: Makes source file locations in reports and messages, like the
+///     `--list-json` inventory and soft assertion failures, relative to the root, which defaults to the current directory.
+///     Locations outside of the root are kept as they are. It keeps the reports comparable across machines.
+///   - `--auto-verbosity`: Holds back the console output until the run ends. If every case passed, only the output of
+///     `--quiet-run` is printed, otherwise the whole detailed output is. The summary file gets the same output as the console.
+///     Has no effect together with `--quiet-run`.
+///   - `--always-summary`: Prints the counts of the whole run once all reporters finished, even with `--quiet-run`. It keeps the
+///     human-readable result as the last lines of the console output, also when `--format=json` or `--format=junit` print the
+///     report to stdout after the cases.
+///   - `--describe=test_name` or `--describe=file.rs::test_name`: Prints everything known about a single registered test, like its
+///     location, description, tags, timeout, retries, dependencies and the settings of its suite, and exits without running
+///     anything. With `--compare-report`, the outcome of the test in that report is included. Fails if no test or more than one
+///     matches the name.
+///   - `--check-config`: Validates the command line arguments merged with the properties of the runner, including the files they
+///     reference, like `--meta-file`, `--compare-report` or `--bench-baseline`. Prints the resolved configuration and exits
+///     without running any case, with exit code `0` if it's valid and `1` with the errors otherwise. Doesn't create any file or
+///     the `--artifacts-dir` directory.
+///   - `--list-suites`: Prints every suite, which is the file the cases are defined in, with the count of its selected tests and
+///     benchmarks, and exits without running them. All filters are honored, so it can be used to pick `--suite` targets.
+///   - `--meta=key=value`: Tags the run with arbitrary metadata, echoed at the start of the run. Can be repeated, but every key
+///     can be provided only once.
+///   - `--meta-file=path/to/meta.txt`: Loads metadata from the file, containing either a JSON object or `key=value` lines. Keys
+///     provided with `--meta` take precedence over the ones from the file.
+///
+/// ## Run hooks
+///
+/// Functions set with [`GdTestRunner::set_before_run`] and [`GdTestRunner::set_after_run`] are called exactly once, before
+/// and after all cases. Panic in `before_run` aborts the run with no case executed, while `after_run` is called regardless.
+///
+/// ## Custom reporters
+///
+/// Besides the built-in console, JSON and JUnit output, results can be consumed by custom [`Reporter`] implementations,
+/// registered with [`GdTestRunner::add_reporter`] before the run starts.
 ///
 #[derive(GodotClass)]
 #[class(base=Node)]
@@ -179,6 +551,22 @@ pub struct GdTestRunner {
     config: RunnerConfig,
     failed_list: Vec<String>,
     began_run: bool,
+    writer: MessageWriter,
+    bench_baseline: BenchBaseline,
+    bench_medians: Vec<(String, Duration)>,
+    /// Medians of passed benchmarks belonging to a group, as `(group, name, median)`.
+    bench_groups: Vec<(&'static str, &'static str, Duration)>,
+    report: RunReport,
+    reporters: Vec<Box<dyn Reporter>>,
+    /// Whether the report was passed to the reporters, which doesn't happen on early exits.
+    reports_written: bool,
+    custom_args: Vec<CustomArg>,
+    before_run: Option<fn()>,
+    after_run: Option<fn()>,
+    display_name_hook: Option<DisplayNameHook>,
+    watchdog: Option<Watchdog>,
+    /// Count of cases which didn't run because the run was interrupted.
+    not_run: usize,
     base: Base<Node>,
 }
 
@@ -199,6 +587,19 @@ impl INode for GdTestRunner {
             config: RunnerConfig::default(),
             failed_list: Vec::new(),
             began_run: false,
+            writer: MessageWriter::new(false),
+            bench_baseline: BenchBaseline::default(),
+            bench_medians: Vec::new(),
+            bench_groups: Vec::new(),
+            report: RunReport::default(),
+            reporters: Vec::new(),
+            reports_written: false,
+            custom_args: Vec::new(),
+            before_run: None,
+            after_run: None,
+            display_name_hook: None,
+            watchdog: None,
+            not_run: 0,
             base,
         }
     }
@@ -211,8 +612,79 @@ impl INode for GdTestRunner {
 
 #[godot_api]
 impl GdTestRunner {
+    /// Runs all qualified tests and benchmarks, returning counts of their outcomes as `Dictionary` with `passed`, `failed`,
+    /// `skipped` and `timed_out` keys.
+    #[func]
+    fn test_run(&mut self) -> Dictionary {
+        self.run();
+        self.run_counts()
+    }
+
+    /// Counts of case outcomes, in the format returned by `test_run`.
     #[func]
-    fn test_run(&mut self) {
+    fn run_counts(&self) -> Dictionary {
+        self.report.counts().to_dictionary()
+    }
+
+    /// Registers custom [`Reporter`], which will receive the results alongside the built-in ones.
+    ///
+    /// Needs to be called before the run starts, e.g. in the `ready()` of the node owning the runner.
+    pub fn add_reporter(&mut self, reporter: impl Reporter + 'static) {
+        self.reporters.push(Box::new(reporter));
+    }
+
+    /// Declares project-specific command line argument, which will be accepted by the runner instead of failing the run as
+    /// unrecognized. If `takes_value` is set, it's provided as `--name=value`, otherwise as a bare `--name` flag. Values are
+    /// available to tests with [`TestContext::custom_arg`].
+    ///
+    /// Needs to be called before the run starts, e.g. in the `ready()` of the node owning the runner.
+    pub fn add_custom_arg(&mut self, name: impl Into<String>, takes_value: bool) {
+        self.custom_args.push(CustomArg {
+            name: name.into(),
+            takes_value,
+        });
+    }
+
+    /// Sets function called once before any case is executed. If it panics, no case is executed and the run fails.
+    ///
+    /// Needs to be called before the run starts, e.g. in the `ready()` of the node owning the runner.
+    pub fn set_before_run(&mut self, hook: fn()) {
+        self.before_run = Some(hook);
+    }
+
+    /// Sets function transforming the names of cases shown in the output, listings and reports, e.g. to clean up names of
+    /// generated tests. It receives the `display_name` of the test if set, or its canonical name otherwise.
+    ///
+    /// Name filters (the `test_filters` property, `--filters`, `--filters-glob` and `--exclude-glob`), `depends_on` and
+    /// `--order-file` always match the canonical name, as do the keys used to compare runs with `--compare-report` and
+    /// `--bench-baseline`.
+    ///
+    /// Needs to be called before the run starts, e.g. in the `ready()` of the node owning the runner.
+    pub fn set_display_name_hook(&mut self, hook: fn(&str) -> String) {
+        self.display_name_hook = Some(hook);
+    }
+
+    /// Checks whether the built-in command line argument, e.g. `--keyword`, was provided, with or without a value, as opposed
+    /// to being defaulted or taken from the properties of the runner. Useful when layering project-specific configuration on
+    /// top of the runner's.
+    ///
+    /// Arguments are parsed when the run starts. Before that, the raw command line is checked instead.
+    pub fn is_arg_provided(&self, flag: &str) -> bool {
+        if self.began_run {
+            self.config.is_provided(flag)
+        } else {
+            CliConfig::is_arg_requested(flag)
+        }
+    }
+
+    /// Sets function called once after all cases were executed. It's called even if the `before_run` hook panicked.
+    ///
+    /// Needs to be called before the run starts, e.g. in the `ready()` of the node owning the runner.
+    pub fn set_after_run(&mut self, hook: fn()) {
+        self.after_run = Some(hook);
+    }
+
+    fn run(&mut self) {
         if self.began_run {
             return;
         }
@@ -220,33 +692,179 @@ impl GdTestRunner {
         let path = self.base().get_scene_file_path().to_string();
 
         self.began_run = true;
-        let writer = MessageWriter::new(false);
-
-        match RunnerConfig::new(
-            self.disallow_focus,
-            self.disallow_skip,
-            self.run_tests,
-            self.run_benchmarks,
-            &self.test_keyword,
-            self.ignore_keywords,
-            self.only_scene_path,
-            path,
-            &self.test_filters,
-            false,
-        ) {
+        set_display_name_hook(self.display_name_hook);
+        let run_clock = Instant::now();
+        let writer =
+            MessageWriter::new(false).with_stderr_console(CliConfig::is_json_format_requested());
+
+        let config = RunnerConfig::builder()
+            .disallow_focus(self.disallow_focus)
+            .disallow_skip(self.disallow_skip)
+            .run_rust_tests(self.run_tests)
+            .run_rust_benchmarks(self.run_benchmarks)
+            .keyword(self.test_keyword.to_string())
+            .ignore_keywords(self.ignore_keywords)
+            .only_scene_path(self.only_scene_path)
+            .scene_path(path)
+            .filters(
+                self.test_filters
+                    .as_slice()
+                    .iter()
+                    .map(|str| str.to_string()),
+            )
+            .custom_args(self.custom_args.iter().cloned())
+            .build();
+        match config {
             Ok(config) => self.config = config,
             Err(error) => {
-                writer.println(&error.to_string());
-                self.end(1);
+                self.end_with_config_error(&writer, &error);
                 return;
             }
         }
 
-        let writer = MessageWriter::new(self.config.is_quiet());
+        let dots = self.config.report_formats().contains(&ReportFormat::Dots);
+        // Output held back by `--auto-verbosity` is told apart by being quiet.
+        let quiet = self.config.is_quiet() || self.config.auto_verbosity();
+        // Checking the config shouldn't produce any file.
+        let summary_file = self
+            .config
+            .summary_file()
+            .filter(|_| !self.config.check_config());
+        let writer = if let Some(summary_file) = summary_file {
+            match MessageWriter::with_summary_file(quiet, summary_file) {
+                Ok(writer) => writer
+                    .with_max_message_len(self.config.max_message_len())
+                    .with_banner_fill(self.config.banner_fill())
+                    .with_dots(dots)
+                    .with_only_failures(self.config.only_leaf_failures())
+                    .with_time_unit(self.config.time_unit())
+                    .with_auto_verbosity(self.config.auto_verbosity())
+                    .with_stderr_console(self.config.prints_reports_to_stdout()),
+                Err(error) => {
+                    writer.println(&format!(
+                        "couldn't create summary file '{summary_file}': {error}"
+                    ));
+                    self.end(ExitReason::ConfigError);
+                    return;
+                }
+            }
+        } else {
+            MessageWriter::new(quiet)
+                .with_max_message_len(self.config.max_message_len())
+                .with_banner_fill(self.config.banner_fill())
+                .with_dots(dots)
+                .with_only_failures(self.config.only_leaf_failures())
+                .with_time_unit(self.config.time_unit())
+                .with_auto_verbosity(self.config.auto_verbosity())
+                .with_stderr_console(self.config.prints_reports_to_stdout())
+        };
+        self.writer = writer.clone();
+
+        if !self.config.ignored_args().is_empty() {
+            writer.loud().println(&format!(
+                "warning: ignoring unrecognized args: {}",
+                self.config.ignored_args().join(", ")
+            ));
+        }
+
+        let previous_report = match self.config.compare_report().map(RunReport::load) {
+            Some(Ok(report)) => Some(report),
+            Some(Err(error)) => {
+                self.end_with_config_error(&writer, &error);
+                return;
+            }
+            None => None,
+        };
+        // Loaded before any case runs, so a broken baseline doesn't fail the run only after all tests, and is validated by
+        // `--check-config` as well.
+        match self.config.bench_baseline().map(BenchBaseline::load) {
+            Some(Ok(baseline)) => self.bench_baseline = baseline,
+            Some(Err(error)) => {
+                self.end_with_config_error(&writer, &error);
+                return;
+            }
+            None => {}
+        }
+        let rendered_report = match self.config.render_report().map(RunReport::load) {
+            Some(Ok(report)) => Some(report),
+            Some(Err(error)) => {
+                self.end_with_config_error(&writer, &error);
+                return;
+            }
+            None => None,
+        };
+        let merged_reports = match self.config.merge_reports() {
+            [] => None,
+            entries => match RunReport::merge(entries) {
+                Ok(merged) => Some(merged),
+                Err(error) => {
+                    self.end_with_config_error(&writer, &error);
+                    return;
+                }
+            },
+        };
+
+        set_relative_paths_root(self.config.relative_paths());
+        set_percent_precision(self.config.percent_precision());
+        set_require_services(self.config.require_services());
+        set_update_snapshots(self.config.update_snapshots());
+
+        if self.config.check_config() {
+            let writer = writer.loud();
+            writer.print_summary_info(&self.config);
+            writer.println("   Configuration is valid");
+            self.end(ExitReason::AllPassed);
+            return;
+        }
+
+        if let Some(name) = self.config.describe() {
+            match Self::describe_test(
+                &writer.loud(),
+                name,
+                previous_report.as_ref(),
+                self.config.compare_report(),
+            ) {
+                Ok(()) => self.end(ExitReason::AllPassed),
+                Err(error) => self.end_with_config_error(&writer, &error),
+            }
+            return;
+        }
+
+        // Listing needs to produce clean output.
+        let banner = !self.config.no_banner() && !self.config.is_listing();
 
-        writer.print_begin();
+        // Console output goes last, so its final banner is printed after errors of the other reporters.
+        let mut all_reporters = reporters(&self.config);
+        all_reporters.append(&mut self.reporters);
+        all_reporters.push(Box::new(HumanReporter::new(writer.clone(), banner)));
+        self.reporters = all_reporters;
 
-        writer.print_summary_info(&self.config);
+        for reporter in self.reporters.iter_mut() {
+            reporter.on_run_start();
+        }
+
+        // Saved report is only passed through the reporters, without running any case.
+        if let (Some(report), Some(path)) = (rendered_report, self.config.render_report()) {
+            writer.print_rendered_report(path, &report);
+            self.report = report;
+            self.finish_reporters();
+            return;
+        }
+        if let Some(merged) = merged_reports {
+            for duplicate in &merged.duplicates {
+                writer.println(&format!(
+                    "warning: case reported by multiple shards: {duplicate}"
+                ));
+            }
+            writer.print_rendered_report(&merged.paths.join(", "), &merged.report);
+            self.report = merged.report;
+            self.finish_reporters();
+            return;
+        }
+
+        if banner {
+            writer.print_summary_info(&self.config);
+        }
 
         let mut rust_test_outcome = true;
         let mut rust_bench_outcome = true;
@@ -267,8 +885,105 @@ impl GdTestRunner {
             rust_bench_handler = Some(handler);
         }
 
+        if self.config.forbid_focus() {
+            let mut focused = Vec::new();
+            if let Some(handler) = &rust_tests_handler {
+                focused.extend(
+                    handler
+                        .tests()
+                        .filter(|test| test.focused)
+                        .map(|test| test.full_name()),
+                );
+            }
+            if let Some(handler) = &rust_bench_handler {
+                focused.extend(
+                    handler
+                        .benches()
+                        .filter(|bench| bench.focused)
+                        .map(BenchBaseline::key),
+                );
+            }
+            if !focused.is_empty() {
+                writer.loud().println(&format!(
+                    "found focused cases, which are forbidden by {}:",
+                    CliConfig::CMD_USER_FORBID_FOCUS
+                ));
+                for case in &focused {
+                    writer.loud().println(&format!("  * {case}"));
+                }
+                self.abort_before_cases(format!(
+                    "focused cases are forbidden by {}: {}",
+                    CliConfig::CMD_USER_FORBID_FOCUS,
+                    focused.join(", ")
+                ));
+                return;
+            }
+        }
+
+        if self.config.fail_empty() {
+            let registered = rust_tests_handler
+                .as_ref()
+                .map_or(0, GdRustItests::tests_count)
+                + rust_bench_handler
+                    .as_ref()
+                    .map_or(0, |handler| handler.benches().count());
+            if registered == 0 {
+                let message = format!(
+                    "no tests or benchmarks are registered, which is forbidden by {}",
+                    CliConfig::CMD_USER_FAIL_EMPTY
+                );
+                writer.loud().println(&message);
+                self.abort_before_cases(message);
+                return;
+            }
+        }
+
+        if !self.config.suites().is_empty() {
+            let unknown = self
+                .config
+                .suites()
+                .iter()
+                .filter(|suite| {
+                    let in_tests = rust_tests_handler
+                        .as_ref()
+                        .is_some_and(|handler| handler.tests().any(|test| test.is_in_suite(suite)));
+                    let in_benches = rust_bench_handler.as_ref().is_some_and(|handler| {
+                        handler.benches().any(|bench| bench.is_in_suite(suite))
+                    });
+                    !in_tests && !in_benches
+                })
+                .cloned()
+                .collect::<Vec<_>>();
+            if !unknown.is_empty() {
+                let error = ConfigError::of_kind(
+                    ConfigErrorKind::InvalidValue,
+                    &[CliConfig::CMD_USER_SUITE],
+                    format!(
+                        "unknown suites for {}: {}",
+                        CliConfig::CMD_USER_SUITE,
+                        unknown.join(", ")
+                    ),
+                );
+                self.end_with_config_error(&writer, &error);
+                return;
+            }
+        }
+
         // Filter tests and benches on path and focus
         if let Some(handler) = &mut rust_tests_handler {
+            if let Some(note) = handler.keyword_selection_note(&self.config) {
+                if self.config.strict_selection() {
+                    let error = ConfigError::new(format!(
+                        "{note} (failing due to {})",
+                        CliConfig::CMD_USER_STRICT_SELECTION
+                    ));
+                    self.end_with_config_error(&writer, &error);
+                    return;
+                }
+                if !self.config.is_listing() {
+                    writer.println(&format!("note: {note}"));
+                }
+            }
             handler.filter_path_keyword(&self.config);
         }
         if let Some(handler) = &mut rust_bench_handler {
@@ -277,18 +992,159 @@ impl GdTestRunner {
 
         // Filter tests and benches on focus and filter
         if let Some(handler) = &mut rust_tests_handler {
+            handler.filter_ignored_suites(self.config.run_ignored());
             handler.set_focus_run(is_focus_run);
             handler.filter_focus_filters(&self.config);
             is_focus_run = handler.is_focus_run();
-            handler.finish_setup();
-            writer.println(&handler.get_post_init_summary());
+            let unknown = handler.unknown_tests(self.config.order_file());
+            if !unknown.is_empty() && !self.config.is_listing() {
+                writer.println(&format!(
+                    "warning: unknown tests in {}: {}",
+                    CliConfig::CMD_USER_ORDER_FILE,
+                    unknown.join(", ")
+                ));
+            }
+            if let Err(error) = handler.finish_setup(
+                self.config.order(),
+                self.config.shuffle_seed().map(|(seed, _)| seed),
+                self.config.order_file(),
+            ) {
+                self.end_with_config_error(&writer, &error);
+                return;
+            }
+            if !self.config.is_listing() {
+                writer.println(&handler.get_post_init_summary());
+            }
         }
         if let Some(handler) = &mut rust_bench_handler {
             handler.set_focus_run(is_focus_run);
             handler.filter_focus_filters(&self.config);
-            handler.finish_setup();
+            handler.finish_setup(
+                self.config.order(),
+                self.config.shuffle_seed().map(|(seed, _)| seed),
+            );
             // is_focus_run = handler.is_focus_run();
-            writer.println(&handler.get_post_init_summary());
+            if !self.config.is_listing() {
+                writer.println(&handler.get_post_init_summary());
+            }
+        }
+
+        if self.config.list_json() {
+            let mut listings = Vec::new();
+            if let Some(handler) = &rust_tests_handler {
+                listings.extend(
+                    handler
+                        .tests()
+                        .map(|test| CaseListing::new(CaseType::RustTest, test, test.line)),
+                );
+            }
+            if let Some(handler) = &rust_bench_handler {
+                listings.extend(
+                    handler
+                        .benches()
+                        .map(|bench| CaseListing::new(CaseType::RustBenchmark, bench, bench.line)),
+                );
+            }
+            println!("{}", CaseListing::render(&listings));
+            self.end(ExitReason::AllPassed);
+            return;
+        }
+
+        if self.config.list_suites() {
+            let mut suites: Vec<(&str, usize, usize)> = Vec::new();
+            let tests = rust_tests_handler
+                .iter()
+                .flat_map(|handler| handler.tests().map(|test| (test.file, true)));
+            let benches = rust_bench_handler
+                .iter()
+                .flat_map(|handler| handler.benches().map(|bench| (bench.file, false)));
+            for (file, is_test) in tests.chain(benches) {
+                let suite = extract_file_subtitle(file);
+                let index = match suites.iter().position(|(name, _, _)| *name == suite) {
+                    Some(index) => index,
+                    None => {
+                        suites.push((suite, 0, 0));
+                        suites.len() - 1
+                    }
+                };
+                if is_test {
+                    suites[index].1 += 1;
+                } else {
+                    suites[index].2 += 1;
+                }
+            }
+            suites.sort_by_key(|(name, _, _)| *name);
+            writer.loud().print_suites_listing(&suites);
+            self.end(ExitReason::AllPassed);
+            return;
+        }
+
+        if self.config.no_run() {
+            let tests = rust_tests_handler.iter().flat_map(|handler| {
+                handler
+                    .tests()
+                    .map(|test| (test.file, test.get_case_display_name()))
+            });
+            let benches = rust_bench_handler.iter().flat_map(|handler| {
+                handler.benches().map(|bench| {
+                    (
+                        bench.file,
+                        format!("{} (benchmark)", bench.get_case_display_name()),
+                    )
+                })
+            });
+            writer.println("");
+            for (file, name) in tests.chain(benches) {
+                writer.println(&format!("   -- {} > {name}", extract_file_subtitle(file)));
+            }
+            writer.println(&format!(
+                "\n   No case was run due to {}",
+                CliConfig::CMD_USER_NO_RUN
+            ));
+            self.end(ExitReason::AllPassed);
+            return;
+        }
+
+        if let Some(Err(error)) = self.before_run.map(handle_panic) {
+            writer
+                .loud()
+                .println(&format!("before_run hook failed: {error}"));
+            let message = "not run, as before_run hook failed".to_owned();
+            if let Some(handler) = rust_tests_handler.take() {
+                for test in handler.tests() {
+                    self.add_report_case(CaseReport::new(
+                        CaseType::RustTest,
+                        test,
+                        &CaseOutcome::Skipped,
+                        Duration::ZERO,
+                        None,
+                        Some(message.clone()),
+                    ));
+                }
+            }
+            if let Some(handler) = rust_bench_handler.take() {
+                for bench in handler.benches() {
+                    self.add_report_case(CaseReport::new(
+                        CaseType::RustBenchmark,
+                        bench,
+                        &CaseOutcome::Skipped,
+                        Duration::ZERO,
+                        None,
+                        Some(message.clone()),
+                    ));
+                }
+            }
+            self.report.aborted = Some(format!("before_run hook failed: {error}"));
+            rust_test_outcome = false;
+        }
+
+        self.watchdog = self
+            .config
+            .stall_timeout()
+            .map(|stall_timeout| Watchdog::start(stall_timeout, writer.detached()));
+        set_panic_detail(self.config.panic_detail());
+        if !self.config.no_signal_handler() {
+            install_handler();
         }
 
         // Run Rust Tests.
@@ -301,9 +1157,17 @@ impl GdTestRunner {
             let clock = Instant::now();
             self.run_rust_tests(&mut handler);
             let run_time = clock.elapsed();
+            writer.print_dots_summary(&self.report);
 
             writer.println("");
-            rust_test_outcome = self.tests_summary.conclude(run_time, &mut self.failed_list);
+            rust_test_outcome = self.tests_summary.conclude(
+                run_time,
+                &mut self.failed_list,
+                self.config.min_pass_rate(),
+                self.config.group_by(),
+                &self.report.cases,
+                &writer,
+            );
         }
 
         // Run Rust Benchmarks.
@@ -318,23 +1182,230 @@ impl GdTestRunner {
             let run_time = clock.elapsed();
 
             writer.println("");
-            rust_bench_outcome = self
-                .benches_summary
-                .conclude(run_time, &mut self.failed_list);
+            rust_bench_outcome = self.benches_summary.conclude(
+                run_time,
+                &mut self.failed_list,
+                self.config.min_pass_rate(),
+                self.config.group_by(),
+                &self.report.cases,
+                &writer,
+            );
+
+            if self.config.bench_group_relative() {
+                writer.print_bench_groups(&self.bench_groups);
+            }
+
+            if let Some(path) = self.config.bench_save_baseline() {
+                if let Err(error) = BenchBaseline::save(path, &self.bench_medians) {
+                    writer.println(&format!(
+                        "couldn't save benchmark baseline '{path}': {error}"
+                    ));
+                    rust_bench_outcome = false;
+                }
+            }
         }
 
-        let outcome = rust_test_outcome && rust_bench_outcome;
+        self.watchdog = None;
+        let after_run_outcome = self.run_after_hook(&writer);
 
-        if outcome {
-            writer.print_success()
-        } else {
-            writer.print_failure()
+        if is_interrupted() {
+            let message = format!("interrupted, {} cases didn't run", self.not_run);
+            writer.loud().println(&format!("\n   Run {message}"));
+            self.report.aborted = Some(message);
+        }
+
+        let within_budget = self.check_total_duration(&writer, run_clock.elapsed());
+        let outcome = rust_test_outcome
+            && rust_bench_outcome
+            && after_run_outcome
+            && within_budget
+            && self.report.aborted.is_none();
+
+        self.report
+            .finish(outcome, run_clock.elapsed(), &self.config);
+
+        if let (Some(previous), Some(path)) = (&previous_report, self.config.compare_report()) {
+            let delta = ReportDelta::new(previous, &self.report, self.config.perf_regression_pct());
+            writer.print_report_delta(path, &delta);
+        }
+
+        if self.config.interactive() && is_interactive_terminal(&writer) {
+            writer.flush_buffer(!self.report.success);
+            browse_failures(&writer, &self.report);
         }
 
-        self.end(!outcome as i32);
+        self.finish_reporters();
+    }
+
+    /// Prints everything known about the registered test matching `name`, for `--describe`. Filters don't apply, so any test
+    /// can be described.
+    fn describe_test(
+        writer: &MessageWriter,
+        name: &str,
+        previous_report: Option<&RunReport>,
+        previous_path: Option<&str>,
+    ) -> Result<(), ConfigError> {
+        let handler = GdRustItests::init();
+        let matching = handler
+            .tests()
+            .filter(|test| test.is_dependency(name))
+            .collect::<Vec<_>>();
+        let test = match matching.as_slice() {
+            [test] => *test,
+            [] => {
+                return Err(ConfigError::of_kind(
+                    ConfigErrorKind::InvalidValue,
+                    &[CliConfig::CMD_USER_DESCRIBE],
+                    format!(
+                        "no test matches '{name}' for {}",
+                        CliConfig::CMD_USER_DESCRIBE
+                    ),
+                ))
+            }
+            tests => {
+                let names = tests
+                    .iter()
+                    .map(|test| test.full_name())
+                    .collect::<Vec<_>>();
+                return Err(ConfigError::of_kind(
+                    ConfigErrorKind::InvalidValue,
+                    &[CliConfig::CMD_USER_DESCRIBE],
+                    format!(
+                        "'{name}' matches multiple tests for {}, use `file.rs::test_name` to pick one: {}",
+                        CliConfig::CMD_USER_DESCRIBE,
+                        names.join(", ")
+                    ),
+                ));
+            }
+        };
+
+        let mut suite_notes = Vec::new();
+        if let Some(suite) = ignored_suites()
+            .into_iter()
+            .find(|suite| suite.file == test.file)
+        {
+            suite_notes.push(format!("ignored: {}", suite.reason));
+        }
+        if let Some(suite) = suite_timeouts()
+            .into_iter()
+            .find(|suite| suite.file == test.file)
+        {
+            suite_notes.push(format!("timeout budget of {}s", suite.budget));
+        }
+        let previous = previous_report.zip(previous_path).map(|(report, path)| {
+            let case = report.cases.iter().find(|case| {
+                case.kind == ReportCaseKind::Test
+                    && case.file == extract_file_subtitle(test.file)
+                    && case.name == test.name
+            });
+            (path, case)
+        });
+        writer.print_test_description(test, &suite_notes, previous);
+        Ok(())
+    }
+
+    /// Reports the total duration against the budget from `--max-total-duration-fail`, returning whether it was kept.
+    fn check_total_duration(&self, writer: &MessageWriter, total: Duration) -> bool {
+        let Some(budget) = self.config.max_total_fail() else {
+            return true;
+        };
+        let budget = Duration::from_secs_f64(budget);
+        let (total_display, budget_display) = (
+            writer.format_duration(total, TimeUnit::Seconds),
+            writer.format_duration(budget, TimeUnit::Seconds),
+        );
+        if total <= budget {
+            writer.println(&format!(
+                "\n   Total duration {total_display} within the budget of {budget_display}"
+            ));
+            return true;
+        }
+        writer.loud().println(&format!(
+            "\n   Run failed: total duration {total_display} exceeded the budget of {budget_display} ({})",
+            CliConfig::CMD_USER_MAX_TOTAL_FAIL
+        ));
+        false
+    }
+
+    /// Fails the run before any case was executed. Reporters are already started, so they still get the report, with the
+    /// `reason` it was aborted.
+    fn abort_before_cases(&mut self, reason: String) {
+        self.report.finish(false, Duration::ZERO, &self.config);
+        self.report.aborted = Some(reason);
+        self.write_reports();
+        self.end(ExitReason::ConfigError);
+    }
+
+    /// Passes the finished report to all reporters and ends the run, with the reason based on the report.
+    fn finish_reporters(&mut self) {
+        self.write_reports();
+        let outcome = self.report.success;
+
+        let reason = match (outcome, self.report.counts().failed) {
+            _ if is_interrupted() => ExitReason::Interrupted,
+            _ if self.report.aborted.is_some() => ExitReason::ConfigError,
+            (false, _) => ExitReason::TestFailures,
+            (true, 0) => ExitReason::AllPassed,
+            (true, _) => ExitReason::MinPassRateMet,
+        };
+        self.end(reason);
+    }
+
+    /// Passes the finished report to all reporters.
+    fn write_reports(&mut self) {
+        self.reports_written = true;
+        self.writer.flush_buffer(!self.report.success);
+        for reporter in self.reporters.iter_mut() {
+            if let Err(error) = reporter.on_run_finish(&self.report) {
+                self.writer
+                    .loud()
+                    .println(&format!("couldn't write {}: {error}", reporter.name()));
+                self.report.success = false;
+            }
+        }
+        if self.config.always_summary() {
+            self.writer.loud().print_report_result(&self.report);
+        }
+    }
+
+    /// Prints the error and ends the run. With `--format=json`, the error is also printed to stdout as a JSON object.
+    fn end_with_config_error(&mut self, writer: &MessageWriter, error: &ConfigError) {
+        writer.println(&error.to_string());
+        if CliConfig::is_json_format_requested() {
+            let mut stdout = std::io::stdout().lock();
+            let _ = writeln!(stdout, "{}", error.to_json());
+        }
+        self.end(ExitReason::ConfigError);
     }
 
-    fn end(&mut self, exit_code: i32) {
+    fn end(&mut self, reason: ExitReason) {
+        let exit_code = match reason {
+            ExitReason::AllPassed | ExitReason::MinPassRateMet => 0,
+            ExitReason::TestFailures | ExitReason::ConfigError => 1,
+            ExitReason::Interrupted => INTERRUPTED_EXIT_CODE,
+        };
+        // Stops the watchdog and restores the signal handler on any exit path.
+        self.watchdog = None;
+        restore_handler();
+        self.writer.flush_buffer(exit_code != 0);
+        if !self.reports_written && exit_code != 0 {
+            self.write_early_status(reason);
+        }
+
+        // Config may be unavailable if it couldn't be parsed.
+        let exit_marker = self.config.exit_marker()
+            || (reason == ExitReason::ConfigError && CliConfig::is_exit_marker_requested());
+        if exit_marker {
+            // Printed to stderr, so it doesn't break machine-readable output on stdout, like `--list-json`.
+            self.writer
+                .loud()
+                .with_stderr_console(true)
+                .println(&format!(
+                    "GODOT_TEST_EXIT: failures={} reason={reason}",
+                    self.report.counts().failed
+                ));
+        }
+
         self.base_mut().queue_free();
         self.base()
             .get_tree()
@@ -344,41 +1415,231 @@ impl GdTestRunner {
             .done();
     }
 
-    fn run_rust_tests(&mut self, handler: &mut GdRustItests) {
-        let ctx = TestContext::new(self.base().clone());
+    /// Writes the `--status-file` on failed runs ending before the reporters were finished, e.g. on config errors, so badge
+    /// generators don't show the status of some previous run. Checking the config doesn't write any file.
+    fn write_early_status(&self, reason: ExitReason) {
+        if self.config.check_config()
+            || CliConfig::is_arg_requested(CliConfig::CMD_USER_CHECK_CONFIG)
+        {
+            return;
+        }
+        // Config may be unavailable if it couldn't be parsed.
+        let Some(path) = self.config.status_file().map(str::to_owned).or_else(|| {
+            (reason == ExitReason::ConfigError)
+                .then(CliConfig::requested_status_file)
+                .flatten()
+        }) else {
+            return;
+        };
+        let mut reporter = StatusReporter::new(ReportTarget::File(path));
+        if let Err(error) = reporter.on_run_finish(&self.report) {
+            self.writer
+                .loud()
+                .println(&format!("couldn't write {}: {error}", reporter.name()));
+        }
+    }
 
-        let writer = MessageWriter::new(self.config.is_quiet());
+    fn run_rust_tests(&mut self, handler: &mut GdRustItests) {
+        let writer = self.writer.clone();
         writer.println("");
 
         let mut last_file = None;
+        let mut executed: Vec<(RustTestCase, bool)> = Vec::new();
+        let suite_timeouts = suite_timeouts();
+        let mut suite_elapsed: HashMap<&'static str, Duration> = HashMap::new();
         while let Some(test) = handler.get_test() {
+            if is_interrupted() {
+                self.not_run += handler.tests_count() + 1;
+                break;
+            }
             writer.print_test_pre(test, &mut last_file);
 
-            let result = self.run_rust_test(&test, &ctx);
-            self.tests_summary
-                .update_stats(&test, &result.outcome, &mut self.failed_list);
-            writer.print_test_post(test.name, result);
+            let ctx = TestContext::new(
+                self.base().clone(),
+                case_seed(self.config.run_seed().0, &test.full_name()),
+                self.config.custom_args().clone(),
+            );
+            if let Some(watchdog) = &self.watchdog {
+                watchdog.case_started(test.full_name());
+            }
+            let retries = test.retries.unwrap_or(self.config.retries());
+            let mut attempt = 0;
+            let clock = Instant::now();
+            let (result, duration, attempt_duration) = loop {
+                let attempt_clock = Instant::now();
+                ctx.start_attempt();
+                let over_budget = self.check_suite_budget(&test, &suite_timeouts, &suite_elapsed);
+                let result =
+                    match over_budget.or_else(|| Self::check_dependencies(&test, &executed)) {
+                        Some(skipped) => skipped,
+                        None => self.run_rust_test(&test, &ctx),
+                    };
+                let result = self.check_timeout(&test, &ctx, result, attempt_clock.elapsed());
+                let retried_kind = result.failure_kind().is_some_and(|kind| {
+                    self.config.retry_on().is_empty() || self.config.retry_on().contains(&kind)
+                });
+                if result.outcome != CaseOutcome::Failed || attempt >= retries || !retried_kind {
+                    break (result, clock.elapsed(), attempt_clock.elapsed());
+                }
+                attempt += 1;
+            };
+            if attempt > 0 && result.outcome == CaseOutcome::Passed {
+                self.tests_summary
+                    .add_passed_on_retry(&test, attempt, retries);
+            }
+            *suite_elapsed.entry(test.file).or_default() += duration;
+            if let Some(warn_timeout) = self.config.timeout_warn() {
+                if result.outcome == CaseOutcome::Passed
+                    && warn_timeout > 0.0
+                    && attempt_duration.as_secs_f64() > warn_timeout
+                {
+                    self.tests_summary
+                        .add_slow_warning(&test, attempt_duration, warn_timeout);
+                }
+            }
+            if let Some(watchdog) = &self.watchdog {
+                watchdog.case_finished();
+            }
+            executed.push((test, result.outcome == CaseOutcome::Passed));
+            self.tests_summary.update_stats(
+                &test,
+                &result.outcome,
+                result.failure_kind(),
+                result.sub_failures(),
+                &mut self.failed_list,
+            );
+            self.add_report_case(
+                CaseReport::new(
+                    CaseType::RustTest,
+                    &test,
+                    &result.outcome,
+                    duration,
+                    result.failure_kind(),
+                    result.error.as_ref().map(|err| err.to_string()),
+                )
+                .with_sub_failures(result.sub_failures()),
+            );
+            writer.print_test_post(&test, result);
+        }
+    }
+
+    /// Returns skipped result if the tests of the suite took longer than its budget, from
+    /// [`suite_timeout!`](crate::suite_timeout) or `--suite-timeout`, so far.
+    fn check_suite_budget(
+        &mut self,
+        test: &RustTestCase,
+        suite_timeouts: &[SuiteTimeout],
+        suite_elapsed: &HashMap<&'static str, Duration>,
+    ) -> Option<TestResult> {
+        let budget = suite_timeouts
+            .iter()
+            .find(|suite| suite.file == test.get_case_file())
+            .map(|suite| suite.budget)
+            .or(self.config.suite_timeout())
+            .filter(|budget| *budget > 0.0)?;
+        let elapsed = suite_elapsed.get(test.get_case_file())?;
+        if elapsed.as_secs_f64() <= budget {
+            return None;
         }
+        self.tests_summary.add_suite_abort(test, budget);
+        Some(TestResult::skipped_because(format!(
+            "suite took {:.3}s, exceeding its timeout budget of {budget}s",
+            elapsed.as_secs_f64()
+        )))
+    }
+
+    /// Returns skipped result if any of the test dependencies didn't pass. Dependencies are ordered to be executed first.
+    fn check_dependencies(
+        test: &RustTestCase,
+        executed: &[(RustTestCase, bool)],
+    ) -> Option<TestResult> {
+        for dependency in test.depends_on {
+            let mut results = executed
+                .iter()
+                .filter(|(t, _)| t.is_dependency(dependency))
+                .peekable();
+            if results.peek().is_none() {
+                return Some(TestResult::skipped_because(format!(
+                    "prerequisite not run: {dependency}"
+                )));
+            }
+            if let Some((failed, _)) = results.find(|(_, passed)| !passed) {
+                return Some(TestResult::skipped_because(format!(
+                    "prerequisite failed: {}",
+                    failed.full_name()
+                )));
+            }
+        }
+        None
     }
 
     fn run_rust_test(&self, test: &RustTestCase, ctx: &TestContext) -> TestResult {
         if !test.should_run_skip(self.config.disallow_skip()) {
             return TestResult::skipped();
         }
+        if let Some(mode) = test.requires {
+            if mode.is_headless() != is_headless_run() {
+                let reason = format!("requires {mode}");
+                return if self.config.fail_on_mode_skip() {
+                    TestResult::failed(UnwindError::new(format!(
+                        "test {reason}, which isn't the mode of the current run"
+                    )))
+                } else {
+                    TestResult::skipped_because(reason)
+                };
+            }
+        }
 
         let result = super::panic::handle_panic(|| (test.function)(ctx));
 
-        if let Err(err) = result {
-            TestResult::failed(err)
-        } else {
-            TestResult::success()
+        match result {
+            Err(err) if err.is_skipped() && self.config.disallow_skip() => {
+                TestResult::failed(UnwindError::new(format!(
+                    "test skipped itself, which is disallowed: {}",
+                    err.message()
+                )))
+            }
+            Err(err) if err.is_skipped() => TestResult::skipped_because(err.message().to_owned()),
+            Err(err) => TestResult::failed(err),
+            Ok(()) => TestResult::success(),
         }
     }
 
+    /// Fails the passed test if it took longer than its timeout. Tests can't be interrupted while running, so this is
+    /// checked only after the test finished.
+    fn check_timeout(
+        &self,
+        test: &RustTestCase,
+        ctx: &TestContext,
+        result: TestResult,
+        duration: Duration,
+    ) -> TestResult {
+        if result.outcome != CaseOutcome::Passed {
+            return result;
+        }
+        let Some((timeout, source)) = test.effective_timeout(self.config.timeout()) else {
+            return result;
+        };
+        if duration.as_secs_f64() <= timeout {
+            return result;
+        }
+        let checkpoint = match ctx.last_checkpoint() {
+            Some((label, reached)) => format!(
+                "; last checkpoint '{label}' reached after {:.3}s",
+                reached.as_secs_f64()
+            ),
+            None => "; no checkpoint reached".to_owned(),
+        };
+        TestResult::timed_out(format!(
+            "test took {:.3}s, exceeding the {source} of {timeout}s{checkpoint}",
+            duration.as_secs_f64()
+        ))
+    }
+
     fn run_rust_benchmarks(&mut self, benchmarks: &mut GdBenchmarks) {
         let mut ctx = BenchContext::new(self.base().clone());
 
-        let writer = MessageWriter::new(self.config.is_quiet());
+        let writer = self.writer.clone();
 
         let mut first_line = " ".repeat(36).to_string();
         for metrics in BenchResult::metrics() {
@@ -388,14 +1649,109 @@ impl GdTestRunner {
 
         let mut last_file = None;
         while let Some(bench) = benchmarks.get_benchmark() {
+            if is_interrupted() {
+                self.not_run += benchmarks.bench_count() + 1;
+                break;
+            }
             writer.print_bench_pre(&bench, &mut last_file);
 
+            if let Some(watchdog) = &self.watchdog {
+                watchdog.case_started(BenchBaseline::key(&bench));
+            }
+            let clock = Instant::now();
             let result = self.run_rust_benchmark(&bench, &mut ctx);
+            let duration = clock.elapsed();
+            if let Some(watchdog) = &self.watchdog {
+                watchdog.case_finished();
+            }
+            let result = self.check_bench_timeout(result, duration);
+            let mut result = self.compare_to_baseline(&bench, result);
+
+            self.benches_summary.update_stats(
+                &bench,
+                &result.outcome,
+                result.failure_kind(),
+                &[],
+                &mut self.failed_list,
+            );
+            let mut case_report = CaseReport::new(
+                CaseType::RustBenchmark,
+                &bench,
+                &result.outcome,
+                duration,
+                result.failure_kind(),
+                result.error.as_ref().map(|err| err.to_string()),
+            );
+            if result.outcome == CaseOutcome::Passed {
+                case_report = case_report.with_median(result.median());
+                if let Some(group) = bench.group {
+                    self.bench_groups.push((group, bench.name, result.median()));
+                }
+            }
+            self.add_report_case(case_report);
+            let samples = std::mem::take(&mut result.samples);
+            writer.print_bench_post(&bench.get_case_display_name(), result);
+            if self.config.bench_histogram() {
+                writer.print_bench_histogram(&samples);
+            }
+        }
+    }
+
+    /// Calls the `after_run` hook, if set. Returns `false` if it panicked.
+    fn run_after_hook(&self, writer: &MessageWriter) -> bool {
+        if let Some(Err(error)) = self.after_run.map(handle_panic) {
+            writer
+                .loud()
+                .println(&format!("after_run hook failed: {error}"));
+            return false;
+        }
+        true
+    }
+
+    fn add_report_case(&mut self, case: CaseReport) {
+        for reporter in self.reporters.iter_mut() {
+            reporter.on_test_finish(&case);
+        }
+        self.report.add_case(case);
+    }
 
-            self.benches_summary
-                .update_stats(&bench, &result.outcome, &mut self.failed_list);
-            writer.print_bench_post(bench.get_case_name(), result);
+    /// Fails the passed benchmark if it took longer than `--bench-timeout`. Like with tests, it's checked only after the benchmark
+    /// finished.
+    fn check_bench_timeout(&self, result: BenchResult, duration: Duration) -> BenchResult {
+        match self.config.bench_timeout() {
+            Some(timeout)
+                if result.outcome == CaseOutcome::Passed
+                    && timeout > 0.0
+                    && duration.as_secs_f64() > timeout =>
+            {
+                BenchResult::failed(BenchError::Timeout(format!(
+                    "benchmark took {:.3}s, exceeding the benchmark timeout of {timeout}s",
+                    duration.as_secs_f64()
+                )))
+            }
+            _ => result,
+        }
+    }
+
+    fn compare_to_baseline(&mut self, bench: &RustBenchmark, result: BenchResult) -> BenchResult {
+        if result.outcome != CaseOutcome::Passed {
+            return result;
+        }
+        let key = BenchBaseline::key(bench);
+        let median = result.median();
+        let baseline = self.bench_baseline.get(&key);
+        self.bench_medians.push((key, median));
+
+        if let Some(baseline) = baseline {
+            let comparison = BenchComparison::new(
+                baseline,
+                median,
+                self.config.bench_warn_pct(),
+                self.config.bench_fail_pct(),
+            );
+            return result.compare(comparison);
         }
+        result
     }
 
     fn run_rust_benchmark(&self, bench: &RustBenchmark, ctx: &mut BenchContext) -> BenchResult {
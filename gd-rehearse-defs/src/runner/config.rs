@@ -4,24 +4,231 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-use super::{is_godot_debug, is_headless_run, is_rust_debug};
+use crate::cases::FailureKind;
+
+use super::glob::GlobPattern;
+use super::panic::PanicDetail;
+use super::report::{ReportFormat, RUNNER_VERSION};
+use super::shuffle::{random_seed, SeedSource};
+use super::{format_percent, is_godot_debug, is_headless_run, is_rust_debug};
 use core::fmt;
 use godot::builtin::{GString, PackedStringArray};
+use serde::Serialize;
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Category of a [`ConfigError`], included in its JSON form.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConfigErrorKind {
+    UnrecognizedArgs,
+    InvalidValue,
+    /// Arguments which can't be used together.
+    ConflictingArgs,
+    /// Argument used without the other one it requires.
+    MissingArg,
+    /// File referenced by an argument couldn't be read or written, or is malformed.
+    File,
+    Other,
+}
+
+#[derive(Debug, Serialize)]
+struct ConfigErrorEntry {
+    kind: ConfigErrorKind,
+    message: String,
+    /// Arguments which caused the error.
+    args: Vec<String>,
+}
 
 #[derive(Debug)]
 pub struct ConfigError {
-    message: String,
+    entries: Vec<ConfigErrorEntry>,
 }
 
 impl ConfigError {
     pub fn new(message: String) -> Self {
-        Self { message }
+        Self::of_kind(ConfigErrorKind::Other, &[], message)
+    }
+
+    pub(crate) fn of_kind(kind: ConfigErrorKind, args: &[&str], message: String) -> Self {
+        Self {
+            entries: vec![ConfigErrorEntry {
+                kind,
+                message,
+                args: args.iter().map(|arg| (*arg).to_owned()).collect(),
+            }],
+        }
+    }
+
+    /// Kinds of all errors merged into this one.
+    pub fn kinds(&self) -> impl Iterator<Item = ConfigErrorKind> + '_ {
+        self.entries.iter().map(|entry| entry.kind)
+    }
+
+    /// Renders the error as a single line JSON object, for `--format=json`.
+    pub(crate) fn to_json(&self) -> String {
+        serde_json::json!({
+            "type": "config_error",
+            "errors": self.entries,
+        })
+        .to_string()
+    }
+
+    /// Merges all `errors` into a single one, to report them at once.
+    pub(crate) fn combine(errors: Vec<ConfigError>) -> Result<(), ConfigError> {
+        if errors.is_empty() {
+            return Ok(());
+        }
+        Err(Self {
+            entries: errors.into_iter().flat_map(|err| err.entries).collect(),
+        })
     }
 }
 
 impl fmt::Display for ConfigError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "error during config parsing: {}", self.message)
+        match self.entries.as_slice() {
+            [entry] => write!(f, "error during config parsing: {}", entry.message),
+            entries => write!(
+                f,
+                "{} errors during config parsing:\n  - {}",
+                entries.len(),
+                entries
+                    .iter()
+                    .map(|entry| entry.message.as_str())
+                    .collect::<Vec<_>>()
+                    .join("\n  - ")
+            ),
+        }
+    }
+}
+
+/// How filters are combined: description filters with name filters, or the name filters among themselves.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub(crate) enum FiltersCombination {
+    #[default]
+    And,
+    Or,
+}
+
+impl FiltersCombination {
+    fn from_arg(value: &str) -> Option<Self> {
+        match value {
+            "and" => Some(Self::And),
+            "or" => Some(Self::Or),
+            _ => None,
+        }
+    }
+
+    /// Parses the `--filters-mode` value, where case name needs to contain `any` or `all` of the name filters.
+    fn from_match_arg(value: &str) -> Option<Self> {
+        match value {
+            "any" => Some(Self::Or),
+            "all" => Some(Self::And),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for FiltersCombination {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FiltersCombination::And => f.write_str("AND"),
+            FiltersCombination::Or => f.write_str("OR"),
+        }
+    }
+}
+
+/// How the details of finished cases are organized at the end of the run.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub(crate) enum GroupBy {
+    /// Only failures are listed, in execution order.
+    #[default]
+    Order,
+    /// All cases, clustered by their outcome - failures first.
+    Outcome,
+    /// All cases, clustered by the file they are defined in.
+    Suite,
+}
+
+impl GroupBy {
+    fn from_arg(value: &str) -> Option<Self> {
+        match value {
+            "order" => Some(Self::Order),
+            "outcome" => Some(Self::Outcome),
+            "suite" => Some(Self::Suite),
+            _ => None,
+        }
+    }
+}
+
+/// Unit of durations in the human-readable output, selected with `--time-unit`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum TimeUnit {
+    /// Largest unit in which the value is at least `1`.
+    Auto,
+    Seconds,
+    Millis,
+    Micros,
+    Nanos,
+}
+
+impl TimeUnit {
+    fn from_arg(value: &str) -> Option<Self> {
+        match value {
+            "auto" => Some(Self::Auto),
+            "s" => Some(Self::Seconds),
+            "ms" => Some(Self::Millis),
+            "us" => Some(Self::Micros),
+            "ns" => Some(Self::Nanos),
+            _ => None,
+        }
+    }
+
+    pub fn format(self, duration: Duration) -> String {
+        let unit = match self {
+            TimeUnit::Auto if duration >= Duration::from_secs(1) => TimeUnit::Seconds,
+            TimeUnit::Auto if duration >= Duration::from_millis(1) => TimeUnit::Millis,
+            TimeUnit::Auto if duration >= Duration::from_micros(1) => TimeUnit::Micros,
+            TimeUnit::Auto => TimeUnit::Nanos,
+            unit => unit,
+        };
+        match unit {
+            TimeUnit::Seconds => format!("{:.2}s", duration.as_secs_f64()),
+            TimeUnit::Millis => format!("{:.3}ms", duration.as_secs_f64() * 1e3),
+            TimeUnit::Micros => format!("{:.3}μs", duration.as_secs_f64() * 1e6),
+            TimeUnit::Auto | TimeUnit::Nanos => format!("{}ns", duration.as_nanos()),
+        }
+    }
+}
+
+/// Command line argument declared by the embedder, recognized alongside the built-in ones.
+#[derive(Clone, Debug)]
+pub(crate) struct CustomArg {
+    pub name: String,
+    /// Whether the argument is provided as `name=value`, or as a bare flag.
+    pub takes_value: bool,
+}
+
+/// Order of execution for runs which aren't shuffled. Cases with higher priority are always executed first.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub(crate) enum CaseOrder {
+    /// Sorted by the file path, then line and name of the case.
+    #[default]
+    Sorted,
+    /// Order in which the cases were registered, which depends on the build.
+    Defined,
+}
+
+impl CaseOrder {
+    fn from_arg(value: &str) -> Option<Self> {
+        match value {
+            "sorted" => Some(Self::Sorted),
+            "defined" => Some(Self::Defined),
+            _ => None,
+        }
     }
 }
 
@@ -29,6 +236,10 @@ impl fmt::Display for ConfigError {
 pub(crate) struct CliConfig {
     disallow_focus: bool,
     allow_focus: bool,
+    forbid_focus: bool,
+    fail_empty: bool,
+    require_services: bool,
+    update_snapshots: bool,
     disallow_skip: bool,
     allow_skip: bool,
     mute_keyword: bool,
@@ -39,7 +250,76 @@ pub(crate) struct CliConfig {
     only_scene_path: bool,
     keyword: String,
     filters: Vec<String>,
+    filters_glob: Vec<GlobPattern>,
+    exclude_glob: Vec<GlobPattern>,
+    filters_mode: Option<FiltersCombination>,
+    group_by: Option<GroupBy>,
+    order: Option<CaseOrder>,
+    order_file: Vec<String>,
     quiet_run: bool,
+    auto_verbosity: bool,
+    always_summary: bool,
+    meta: Vec<(String, String)>,
+    min_pass_rate: Option<f64>,
+    description_filters: Vec<String>,
+    description_filters_mode: Option<FiltersCombination>,
+    summary_file: Option<String>,
+    strict_cli: bool,
+    priority_threshold: Option<i32>,
+    shard: Option<(u64, u64)>,
+    bench_baseline: Option<String>,
+    bench_save_baseline: Option<String>,
+    compare_report: Option<String>,
+    render_report: Option<String>,
+    merge_reports: Vec<String>,
+    describe: Option<String>,
+    bench_warn_pct: Option<f64>,
+    bench_fail_pct: Option<f64>,
+    perf_regression_pct: Option<f64>,
+    report_formats: Vec<ReportFormat>,
+    json_report: Option<String>,
+    junit_report: Option<String>,
+    csv_report: Option<String>,
+    status_file: Option<String>,
+    artifacts_dir: Option<String>,
+    no_banner: bool,
+    timeout: Option<f64>,
+    timeout_warn: Option<f64>,
+    suite_timeout: Option<f64>,
+    bench_timeout: Option<f64>,
+    max_total_fail: Option<f64>,
+    interactive: bool,
+    shuffle: bool,
+    seed: Option<u64>,
+    exit_marker: bool,
+    retries: Option<u32>,
+    retry_on: Vec<FailureKind>,
+    list_json: bool,
+    list_suites: bool,
+    max_message_len: Option<usize>,
+    percent_precision: Option<usize>,
+    ignored_args: Vec<String>,
+    bench_histogram: bool,
+    bench_group_relative: bool,
+    bench_filters: Vec<String>,
+    suites: Vec<String>,
+    stall_timeout: Option<f64>,
+    banner_fill: Option<char>,
+    no_signal_handler: bool,
+    only_leaf_failures: bool,
+    sort_output: bool,
+    fail_on_mode_skip: bool,
+    strict_selection: bool,
+    smoke: bool,
+    check_config: bool,
+    time_unit: Option<TimeUnit>,
+    no_run: bool,
+    run_ignored: bool,
+    panic_detail: Option<PanicDetail>,
+    relative_paths: Option<String>,
+    custom_args: Vec<(String, String)>,
+    /// Names of the built-in arguments which were provided.
+    provided: BTreeSet<String>,
 }
 
 impl CliConfig {
@@ -47,6 +327,10 @@ impl CliConfig {
     pub const CMD_USER_RUST_BENCHMARKS: &'static str = "--rust-bench";
     pub const CMD_USER_DISALLOW_FOCUS: &'static str = "--disallow-focus";
     pub const CMD_USER_ALLOW_FOCUS: &'static str = "--allow-focus";
+    pub const CMD_USER_FORBID_FOCUS: &'static str = "--forbid-focus";
+    pub const CMD_USER_FAIL_EMPTY: &'static str = "--fail-empty";
+    pub const CMD_USER_REQUIRE_SERVICES: &'static str = "--require-services";
+    pub const CMD_USER_UPDATE_SNAPSHOTS: &'static str = "--update-snapshots";
     pub const CMD_USER_DISALLOW_SKIP: &'static str = "--disallow-skip";
     pub const CMD_USER_ALLOW_SKIP: &'static str = "--allow-skip";
     pub const CMD_USER_MUTE_KEYWORD: &'static str = "--mute-keyword";
@@ -54,36 +338,95 @@ impl CliConfig {
     pub const CMD_USER_MUTE_FILTERS: &'static str = "--mute-filters";
     pub const CMD_USER_KEYWORD: &'static str = "--keyword";
     pub const CMD_USER_FILTERS: &'static str = "--filters";
+    pub const CMD_USER_FILTERS_GLOB: &'static str = "--filters-glob";
+    pub const CMD_USER_EXCLUDE_GLOB: &'static str = "--exclude-glob";
+    pub const CMD_USER_FILTERS_MODE: &'static str = "--filters-mode";
+    pub const CMD_USER_GROUP_BY: &'static str = "--group-by";
+    pub const CMD_USER_ORDER: &'static str = "--order";
+    pub const CMD_USER_ORDER_FILE: &'static str = "--order-file";
     pub const CMD_USER_ONLY_SCENE_PATH: &'static str = "--only-scene-path";
     pub const CMD_USER_QUIET_RUN: &'static str = "--quiet-run";
+    pub const CMD_USER_AUTO_VERBOSITY: &'static str = "--auto-verbosity";
+    pub const CMD_USER_ALWAYS_SUMMARY: &'static str = "--always-summary";
+    pub const CMD_USER_META: &'static str = "--meta";
+    pub const CMD_USER_META_FILE: &'static str = "--meta-file";
+    pub const CMD_USER_MIN_PASS_RATE: &'static str = "--min-pass-rate";
+    pub const CMD_USER_FILTER_DESC: &'static str = "--filter-desc";
+    pub const CMD_USER_FILTER_DESC_MODE: &'static str = "--filter-desc-mode";
+    pub const CMD_USER_SUMMARY_FILE: &'static str = "--summary-file";
+    pub const CMD_USER_STRICT_CLI: &'static str = "--strict-cli";
+    pub const CMD_USER_PRIORITY_THRESHOLD: &'static str = "--priority-threshold";
+    pub const CMD_USER_SHARD: &'static str = "--shard";
+    pub const CMD_USER_BENCH_BASELINE: &'static str = "--bench-baseline";
+    pub const CMD_USER_BENCH_HISTOGRAM: &'static str = "--bench-histogram";
+    pub const CMD_USER_BENCH_GROUP_RELATIVE: &'static str = "--bench-group-relative";
+    pub const CMD_USER_BENCH_FILTER: &'static str = "--bench-filter";
+    pub const CMD_USER_SUITE: &'static str = "--suite";
+    pub const CMD_USER_COMPARE_REPORT: &'static str = "--compare-report";
+    pub const CMD_USER_RENDER_REPORT: &'static str = "--render-report";
+    pub const CMD_USER_MERGE_REPORTS: &'static str = "--merge-reports";
+    pub const CMD_USER_DESCRIBE: &'static str = "--describe";
+    pub const CMD_USER_BENCH_SAVE_BASELINE: &'static str = "--bench-save-baseline";
+    pub const CMD_USER_BENCH_WARN_PCT: &'static str = "--bench-warn-pct";
+    pub const CMD_USER_PERF_REGRESSION_PCT: &'static str = "--perf-regression-pct";
+    pub const CMD_USER_BENCH_FAIL_PCT: &'static str = "--bench-fail-pct";
+    pub const CMD_USER_FORMAT: &'static str = "--format";
+    pub const CMD_USER_JSON: &'static str = "--json";
+    pub const CMD_USER_JUNIT: &'static str = "--junit";
+    pub const CMD_USER_CSV: &'static str = "--csv";
+    pub const CMD_USER_STATUS_FILE: &'static str = "--status-file";
+    pub const CMD_USER_ARTIFACTS_DIR: &'static str = "--artifacts-dir";
+    pub const CMD_USER_NO_BANNER: &'static str = "--no-banner";
+    pub const CMD_USER_TIMEOUT: &'static str = "--timeout";
+    pub const CMD_USER_TIMEOUT_WARN: &'static str = "--timeout-warn";
+    pub const CMD_USER_SUITE_TIMEOUT: &'static str = "--suite-timeout";
+    pub const CMD_USER_BENCH_TIMEOUT: &'static str = "--bench-timeout";
+    pub const CMD_USER_MAX_TOTAL_FAIL: &'static str = "--max-total-duration-fail";
+    pub const CMD_USER_STALL_TIMEOUT: &'static str = "--stall-timeout";
+    pub const CMD_USER_BANNER_FILL: &'static str = "--banner-fill";
+    pub const CMD_USER_NO_SIGNAL_HANDLER: &'static str = "--no-signal-handler";
+    pub const CMD_USER_ONLY_LEAF_FAILURES: &'static str = "--only-leaf-failures";
+    pub const CMD_USER_SORT_OUTPUT: &'static str = "--sort-output";
+    pub const CMD_USER_FAIL_ON_MODE_SKIP: &'static str = "--fail-on-mode-skip";
+    pub const CMD_USER_STRICT_SELECTION: &'static str = "--strict-selection";
+    pub const CMD_USER_SMOKE: &'static str = "--smoke";
+    pub const CMD_USER_CHECK_CONFIG: &'static str = "--check-config";
+    pub const CMD_USER_TIME_UNIT: &'static str = "--time-unit";
+    pub const CMD_USER_NO_RUN: &'static str = "--no-run";
+    pub const CMD_USER_PANIC_DETAIL: &'static str = "--panic-detail";
+    pub const CMD_USER_RUN_IGNORED: &'static str = "--run-ignored";
+    pub const CMD_USER_RELATIVE_PATHS: &'static str = "--relative-paths";
+    pub const CMD_USER_INTERACTIVE: &'static str = "--interactive";
+    pub const CMD_USER_SHUFFLE: &'static str = "--shuffle";
+    pub const CMD_USER_SEED: &'static str = "--seed";
+    pub const CMD_USER_EXIT_MARKER: &'static str = "--exit-marker";
+    pub const CMD_USER_RETRIES: &'static str = "--retries";
+    pub const CMD_USER_RETRY_ON: &'static str = "--retry-on";
+    pub const CMD_USER_LIST_JSON: &'static str = "--list-json";
+    pub const CMD_USER_LIST_SUITES: &'static str = "--list-suites";
+    pub const CMD_USER_MAX_MESSAGE_LEN: &'static str = "--max-message-len";
+    pub const CMD_USER_PERCENT_PRECISION: &'static str = "--percent-precision";
+    pub const CMD_USER_IGNORE_UNKNOWN: &'static str = "--ignore-unknown-args";
 
-    pub fn from_os() -> Result<Self, ConfigError> {
+    pub fn from_os(custom_args: &[CustomArg]) -> Result<Self, ConfigError> {
         let args = godot::engine::Os::singleton().get_cmdline_user_args();
         let mut args_vec = args.as_slice().iter().collect::<Vec<_>>();
+        // All errors are gathered, to report them at once.
+        let mut errors = Vec::new();
 
         let run_rust_tests = Self::get_arg(&mut args_vec, Self::CMD_USER_RUST_TESTS);
         let run_rust_benchmarks = Self::get_arg(&mut args_vec, Self::CMD_USER_RUST_BENCHMARKS);
 
         let allow_focus = Self::get_arg(&mut args_vec, Self::CMD_USER_ALLOW_FOCUS);
         let disallow_focus = Self::get_arg(&mut args_vec, Self::CMD_USER_DISALLOW_FOCUS);
-
-        Self::check_mutually_exclusive_args(
-            allow_focus,
-            disallow_focus,
-            Self::CMD_USER_ALLOW_FOCUS,
-            Self::CMD_USER_DISALLOW_FOCUS,
-        )?;
+        let forbid_focus = Self::get_arg(&mut args_vec, Self::CMD_USER_FORBID_FOCUS);
+        let fail_empty = Self::get_arg(&mut args_vec, Self::CMD_USER_FAIL_EMPTY);
+        let require_services = Self::get_arg(&mut args_vec, Self::CMD_USER_REQUIRE_SERVICES);
+        let update_snapshots = Self::get_arg(&mut args_vec, Self::CMD_USER_UPDATE_SNAPSHOTS);
 
         let allow_skip = Self::get_arg(&mut args_vec, Self::CMD_USER_ALLOW_SKIP);
         let disallow_skip = Self::get_arg(&mut args_vec, Self::CMD_USER_DISALLOW_SKIP);
 
-        Self::check_mutually_exclusive_args(
-            allow_skip,
-            disallow_skip,
-            Self::CMD_USER_ALLOW_SKIP,
-            Self::CMD_USER_DISALLOW_SKIP,
-        )?;
-
         let mute_keyword = Self::get_arg(&mut args_vec, Self::CMD_USER_MUTE_KEYWORD);
         let ignore_keywords = Self::get_arg(&mut args_vec, Self::CMD_USER_IGNORE_KEYWORDS);
 
@@ -94,36 +437,380 @@ impl CliConfig {
             keyword_arg[0].to_owned()
         };
 
-        Self::check_mutually_exclusive_args(
-            mute_keyword,
-            !keyword.is_empty(),
-            Self::CMD_USER_MUTE_KEYWORD,
-            Self::CMD_USER_KEYWORD,
-        )?;
-
         let mute_filters = Self::get_arg(&mut args_vec, Self::CMD_USER_MUTE_FILTERS);
+        let filters_glob = Self::collect(
+            &mut errors,
+            Self::parse_globs(
+                Self::get_arg_with_value(&mut args_vec, Self::CMD_USER_FILTERS_GLOB),
+                Self::CMD_USER_FILTERS_GLOB,
+            ),
+        );
+        let exclude_glob = Self::collect(
+            &mut errors,
+            Self::parse_globs(
+                Self::get_arg_with_value(&mut args_vec, Self::CMD_USER_EXCLUDE_GLOB),
+                Self::CMD_USER_EXCLUDE_GLOB,
+            ),
+        );
+        let filters_mode = Self::collect(
+            &mut errors,
+            Self::parse_filters_match(Self::get_arg_with_value(
+                &mut args_vec,
+                Self::CMD_USER_FILTERS_MODE,
+            )),
+        );
         let filters = Self::get_arg_with_value(&mut args_vec, Self::CMD_USER_FILTERS);
+        let group_by = Self::collect(
+            &mut errors,
+            Self::parse_group_by(Self::get_arg_with_value(
+                &mut args_vec,
+                Self::CMD_USER_GROUP_BY,
+            )),
+        );
+        let order = Self::collect(
+            &mut errors,
+            Self::parse_order(Self::get_arg_with_value(
+                &mut args_vec,
+                Self::CMD_USER_ORDER,
+            )),
+        );
+        let order_file = Self::get_all_args_with_value(&mut args_vec, Self::CMD_USER_ORDER_FILE)
+            .pop()
+            .map(|path| Self::collect(&mut errors, Self::load_order_file(&path)))
+            .unwrap_or_default();
+        let bench_filters = Self::get_arg_with_value(&mut args_vec, Self::CMD_USER_BENCH_FILTER);
+        let mut suites = Vec::new();
+        for value in Self::get_all_args_with_value(&mut args_vec, Self::CMD_USER_SUITE) {
+            for suite in value.split(',').filter(|suite| !suite.is_empty()) {
+                if !suites.iter().any(|added| added == suite) {
+                    suites.push(suite.to_owned());
+                }
+            }
+        }
 
-        Self::check_mutually_exclusive_args(
-            mute_filters,
-            !filters.is_empty(),
-            Self::CMD_USER_MUTE_FILTERS,
-            Self::CMD_USER_FILTERS,
-        )?;
+        let description_filters_mode = Self::collect(
+            &mut errors,
+            Self::parse_filters_combination(Self::get_arg_with_value(
+                &mut args_vec,
+                Self::CMD_USER_FILTER_DESC_MODE,
+            )),
+        );
+        let description_filters =
+            Self::get_arg_with_value(&mut args_vec, Self::CMD_USER_FILTER_DESC);
 
         let only_scene_path = Self::get_arg(&mut args_vec, Self::CMD_USER_ONLY_SCENE_PATH);
 
         let quiet_run = Self::get_arg(&mut args_vec, Self::CMD_USER_QUIET_RUN);
+        let auto_verbosity = Self::get_arg(&mut args_vec, Self::CMD_USER_AUTO_VERBOSITY);
+        let always_summary = Self::get_arg(&mut args_vec, Self::CMD_USER_ALWAYS_SUMMARY);
+        let no_banner = Self::get_arg(&mut args_vec, Self::CMD_USER_NO_BANNER);
+        let banner_fill = Self::collect(
+            &mut errors,
+            Self::parse_banner_fill(
+                Self::get_all_args_with_value(&mut args_vec, Self::CMD_USER_BANNER_FILL).pop(),
+            ),
+        );
+        let interactive = Self::get_arg(&mut args_vec, Self::CMD_USER_INTERACTIVE);
+        let exit_marker = Self::get_arg(&mut args_vec, Self::CMD_USER_EXIT_MARKER);
+        let bench_histogram = Self::get_arg(&mut args_vec, Self::CMD_USER_BENCH_HISTOGRAM);
+        let bench_group_relative =
+            Self::get_arg(&mut args_vec, Self::CMD_USER_BENCH_GROUP_RELATIVE);
+        let list_json = Self::get_arg(&mut args_vec, Self::CMD_USER_LIST_JSON);
+        let list_suites = Self::get_arg(&mut args_vec, Self::CMD_USER_LIST_SUITES);
+        let no_signal_handler = Self::get_arg(&mut args_vec, Self::CMD_USER_NO_SIGNAL_HANDLER);
+        let only_leaf_failures = Self::get_arg(&mut args_vec, Self::CMD_USER_ONLY_LEAF_FAILURES);
+        let sort_output = Self::get_arg(&mut args_vec, Self::CMD_USER_SORT_OUTPUT);
+        let fail_on_mode_skip = Self::get_arg(&mut args_vec, Self::CMD_USER_FAIL_ON_MODE_SKIP);
+        let strict_selection = Self::get_arg(&mut args_vec, Self::CMD_USER_STRICT_SELECTION);
+        let smoke = Self::get_arg(&mut args_vec, Self::CMD_USER_SMOKE);
+        let check_config = Self::get_arg(&mut args_vec, Self::CMD_USER_CHECK_CONFIG);
+        let time_unit = Self::collect(
+            &mut errors,
+            Self::parse_time_unit(Self::get_arg_with_value(
+                &mut args_vec,
+                Self::CMD_USER_TIME_UNIT,
+            )),
+        );
+        let no_run = Self::get_arg(&mut args_vec, Self::CMD_USER_NO_RUN);
+        let run_ignored = Self::get_arg(&mut args_vec, Self::CMD_USER_RUN_IGNORED);
+        let panic_detail = Self::collect(
+            &mut errors,
+            Self::parse_panic_detail(Self::get_arg_with_value(
+                &mut args_vec,
+                Self::CMD_USER_PANIC_DETAIL,
+            )),
+        );
+        // Root is optional, so bare flag needs to be told apart from missing one.
+        let relative_paths = if Self::get_arg(&mut args_vec, Self::CMD_USER_RELATIVE_PATHS) {
+            Some(String::new())
+        } else {
+            let root = Self::get_arg_with_value(&mut args_vec, Self::CMD_USER_RELATIVE_PATHS);
+            (!root.is_empty()).then(|| root.join(","))
+        };
+
+        let shuffle = Self::get_arg(&mut args_vec, Self::CMD_USER_SHUFFLE);
+        let seed = Self::collect(
+            &mut errors,
+            Self::get_arg_with_value(&mut args_vec, Self::CMD_USER_SEED)
+                .first()
+                .map(|value| Self::parse_seed(value, Self::CMD_USER_SEED))
+                .transpose(),
+        );
+
+        let summary_file =
+            Self::get_all_args_with_value(&mut args_vec, Self::CMD_USER_SUMMARY_FILE).pop();
+
+        let mut meta = Self::collect(
+            &mut errors,
+            Self::parse_meta(Self::get_all_args_with_value(
+                &mut args_vec,
+                Self::CMD_USER_META,
+            )),
+        );
+        if let Some(path) =
+            Self::get_all_args_with_value(&mut args_vec, Self::CMD_USER_META_FILE).pop()
+        {
+            // Keys from `--meta` override the ones loaded from file.
+            let file_meta = Self::collect(&mut errors, Self::load_meta_file(&path));
+            let overridden = file_meta
+                .into_iter()
+                .filter(|(key, _)| !meta.iter().any(|(existing, _)| existing == key))
+                .collect::<Vec<_>>();
+            meta.splice(0..0, overridden);
+        }
+
+        let min_pass_rate = Self::collect(
+            &mut errors,
+            Self::parse_percentage(
+                Self::get_arg_with_value(&mut args_vec, Self::CMD_USER_MIN_PASS_RATE),
+                Self::CMD_USER_MIN_PASS_RATE,
+                Some(100.0),
+            ),
+        );
+
+        let bench_baseline =
+            Self::get_all_args_with_value(&mut args_vec, Self::CMD_USER_BENCH_BASELINE).pop();
+        let bench_save_baseline =
+            Self::get_all_args_with_value(&mut args_vec, Self::CMD_USER_BENCH_SAVE_BASELINE).pop();
+        let compare_report =
+            Self::get_all_args_with_value(&mut args_vec, Self::CMD_USER_COMPARE_REPORT).pop();
+        let render_report =
+            Self::get_all_args_with_value(&mut args_vec, Self::CMD_USER_RENDER_REPORT).pop();
+        let merge_reports = Self::get_arg_with_value(&mut args_vec, Self::CMD_USER_MERGE_REPORTS);
+        let describe = Self::get_all_args_with_value(&mut args_vec, Self::CMD_USER_DESCRIBE).pop();
+        let bench_warn_pct = Self::collect(
+            &mut errors,
+            Self::parse_percentage(
+                Self::get_arg_with_value(&mut args_vec, Self::CMD_USER_BENCH_WARN_PCT),
+                Self::CMD_USER_BENCH_WARN_PCT,
+                None,
+            ),
+        );
+        let bench_fail_pct = Self::collect(
+            &mut errors,
+            Self::parse_percentage(
+                Self::get_arg_with_value(&mut args_vec, Self::CMD_USER_BENCH_FAIL_PCT),
+                Self::CMD_USER_BENCH_FAIL_PCT,
+                None,
+            ),
+        );
+        let perf_regression_pct = Self::collect(
+            &mut errors,
+            Self::parse_percentage(
+                Self::get_arg_with_value(&mut args_vec, Self::CMD_USER_PERF_REGRESSION_PCT),
+                Self::CMD_USER_PERF_REGRESSION_PCT,
+                None,
+            ),
+        );
+
+        let timeout = Self::collect(
+            &mut errors,
+            Self::parse_seconds(
+                Self::get_arg_with_value(&mut args_vec, Self::CMD_USER_TIMEOUT),
+                Self::CMD_USER_TIMEOUT,
+            ),
+        );
+        let timeout_warn = Self::collect(
+            &mut errors,
+            Self::parse_seconds(
+                Self::get_arg_with_value(&mut args_vec, Self::CMD_USER_TIMEOUT_WARN),
+                Self::CMD_USER_TIMEOUT_WARN,
+            ),
+        );
+        let suite_timeout = Self::collect(
+            &mut errors,
+            Self::parse_seconds(
+                Self::get_arg_with_value(&mut args_vec, Self::CMD_USER_SUITE_TIMEOUT),
+                Self::CMD_USER_SUITE_TIMEOUT,
+            ),
+        );
+        if let (Some(warn), Some(timeout)) = (timeout_warn, timeout) {
+            // Zero disables the timeout, so any warning budget fits in.
+            if timeout > 0.0 && warn > timeout {
+                errors.push(ConfigError::of_kind(
+                    ConfigErrorKind::ConflictingArgs,
+                    &[Self::CMD_USER_TIMEOUT_WARN, Self::CMD_USER_TIMEOUT],
+                    format!(
+                        "{} needs to be at most {}, got: {warn}s and {timeout}s",
+                        Self::CMD_USER_TIMEOUT_WARN,
+                        Self::CMD_USER_TIMEOUT
+                    ),
+                ));
+            }
+        }
+        let bench_timeout = Self::collect(
+            &mut errors,
+            Self::parse_seconds(
+                Self::get_arg_with_value(&mut args_vec, Self::CMD_USER_BENCH_TIMEOUT),
+                Self::CMD_USER_BENCH_TIMEOUT,
+            ),
+        );
+        let max_total_fail = Self::collect(
+            &mut errors,
+            Self::parse_seconds(
+                Self::get_arg_with_value(&mut args_vec, Self::CMD_USER_MAX_TOTAL_FAIL),
+                Self::CMD_USER_MAX_TOTAL_FAIL,
+            ),
+        );
+        let stall_timeout = Self::collect(
+            &mut errors,
+            Self::parse_seconds(
+                Self::get_arg_with_value(&mut args_vec, Self::CMD_USER_STALL_TIMEOUT),
+                Self::CMD_USER_STALL_TIMEOUT,
+            ),
+        );
+
+        let retries = Self::collect(
+            &mut errors,
+            Self::get_arg_with_value(&mut args_vec, Self::CMD_USER_RETRIES)
+                .first()
+                .map(|value| {
+                    value.parse::<u32>().map_err(|_| {
+                        ConfigError::of_kind(
+                            ConfigErrorKind::InvalidValue,
+                            &[Self::CMD_USER_RETRIES],
+                            format!(
+                                "{} needs to be a non-negative integer, got: '{}'",
+                                Self::CMD_USER_RETRIES,
+                                value
+                            ),
+                        )
+                    })
+                })
+                .transpose(),
+        );
+        let retry_on = Self::collect(
+            &mut errors,
+            Self::parse_retry_on(Self::get_arg_with_value(
+                &mut args_vec,
+                Self::CMD_USER_RETRY_ON,
+            )),
+        );
+
+        let max_message_len = Self::collect(
+            &mut errors,
+            Self::get_arg_with_value(&mut args_vec, Self::CMD_USER_MAX_MESSAGE_LEN)
+                .first()
+                .map(|value| {
+                    value.parse::<usize>().map_err(|_| {
+                        ConfigError::of_kind(
+                            ConfigErrorKind::InvalidValue,
+                            &[Self::CMD_USER_MAX_MESSAGE_LEN],
+                            format!(
+                                "{} needs to be a non-negative integer, got: '{}'",
+                                Self::CMD_USER_MAX_MESSAGE_LEN,
+                                value
+                            ),
+                        )
+                    })
+                })
+                .transpose(),
+        );
+
+        let percent_precision = Self::collect(
+            &mut errors,
+            Self::get_arg_with_value(&mut args_vec, Self::CMD_USER_PERCENT_PRECISION)
+                .first()
+                .map(|value| {
+                    value.parse::<usize>().map_err(|_| {
+                        ConfigError::of_kind(
+                            ConfigErrorKind::InvalidValue,
+                            &[Self::CMD_USER_PERCENT_PRECISION],
+                            format!(
+                                "{} needs to be a non-negative integer, got: '{}'",
+                                Self::CMD_USER_PERCENT_PRECISION,
+                                value
+                            ),
+                        )
+                    })
+                })
+                .transpose(),
+        );
+
+        let priority_threshold = Self::collect(
+            &mut errors,
+            Self::parse_priority_threshold(Self::get_arg_with_value(
+                &mut args_vec,
+                Self::CMD_USER_PRIORITY_THRESHOLD,
+            )),
+        );
+
+        let shard = Self::collect(
+            &mut errors,
+            Self::parse_shard(Self::get_arg_with_value(
+                &mut args_vec,
+                Self::CMD_USER_SHARD,
+            )),
+        );
+
+        let report_formats = Self::collect(
+            &mut errors,
+            Self::parse_report_formats(Self::get_arg_with_value(
+                &mut args_vec,
+                Self::CMD_USER_FORMAT,
+            )),
+        );
+        let json_report = Self::get_all_args_with_value(&mut args_vec, Self::CMD_USER_JSON).pop();
+        let junit_report = Self::get_all_args_with_value(&mut args_vec, Self::CMD_USER_JUNIT).pop();
+        let csv_report = Self::get_all_args_with_value(&mut args_vec, Self::CMD_USER_CSV).pop();
+        let status_file =
+            Self::get_all_args_with_value(&mut args_vec, Self::CMD_USER_STATUS_FILE).pop();
+        let artifacts_dir =
+            Self::get_all_args_with_value(&mut args_vec, Self::CMD_USER_ARTIFACTS_DIR).pop();
+
+        let strict_cli = Self::get_arg(&mut args_vec, Self::CMD_USER_STRICT_CLI);
+        let ignore_unknown = Self::get_arg(&mut args_vec, Self::CMD_USER_IGNORE_UNKNOWN);
+
+        // Every argument consumed so far is a built-in one.
+        let provided = Self::arg_names(
+            args.as_slice()
+                .iter()
+                .filter(|arg| !args_vec.iter().any(|left| std::ptr::eq(*left, *arg))),
+        );
+
+        let custom_args = Self::collect(
+            &mut errors,
+            Self::parse_custom_args(&mut args_vec, custom_args),
+        );
 
         let unrecognized_args = args_vec
             .iter()
             .map(|str| str.to_string())
             .collect::<Vec<_>>();
-        Self::check_unrecognized_args(&unrecognized_args)?;
+        let ignored_args = if ignore_unknown {
+            unrecognized_args
+        } else {
+            errors.extend(Self::check_unrecognized_args(&unrecognized_args).err());
+            Vec::new()
+        };
 
-        Ok(Self {
+        let instance = Self {
             disallow_focus,
             allow_focus,
+            forbid_focus,
+            fail_empty,
+            require_services,
+            update_snapshots,
             disallow_skip,
             allow_skip,
             mute_keyword,
@@ -134,18 +821,727 @@ impl CliConfig {
             only_scene_path,
             keyword,
             filters,
+            filters_glob,
+            exclude_glob,
+            filters_mode,
+            group_by,
+            order,
+            order_file,
             quiet_run,
+            auto_verbosity,
+            always_summary,
+            meta,
+            min_pass_rate,
+            description_filters,
+            description_filters_mode,
+            summary_file,
+            strict_cli,
+            priority_threshold,
+            shard,
+            bench_baseline,
+            compare_report,
+            render_report,
+            merge_reports,
+            describe,
+            bench_save_baseline,
+            bench_warn_pct,
+            bench_fail_pct,
+            perf_regression_pct,
+            report_formats,
+            json_report,
+            junit_report,
+            csv_report,
+            status_file,
+            artifacts_dir,
+            no_banner,
+            timeout,
+            timeout_warn,
+            suite_timeout,
+            bench_timeout,
+            max_total_fail,
+            interactive,
+            shuffle,
+            seed,
+            exit_marker,
+            retries,
+            retry_on,
+            list_json,
+            list_suites,
+            max_message_len,
+            percent_precision,
+            ignored_args,
+            bench_histogram,
+            bench_group_relative,
+            bench_filters,
+            suites,
+            stall_timeout,
+            banner_fill,
+            no_signal_handler,
+            only_leaf_failures,
+            sort_output,
+            fail_on_mode_skip,
+            strict_selection,
+            smoke,
+            check_config,
+            time_unit,
+            no_run,
+            run_ignored,
+            panic_detail,
+            relative_paths,
+            custom_args,
+            provided,
+        };
+
+        // Combinations are meaningful only if all values were parsed.
+        if errors.is_empty() {
+            errors.extend(instance.check_combinations().err());
+        }
+        ConfigError::combine(errors)?;
+
+        Ok(instance)
+    }
+
+    /// Checks the raw command line for `--format=json`, as config errors are reported before the config is parsed.
+    pub fn is_json_format_requested() -> bool {
+        godot::engine::Os::singleton()
+            .get_cmdline_user_args()
+            .as_slice()
+            .iter()
+            .any(|arg| {
+                arg.to_string()
+                    .strip_prefix(Self::CMD_USER_FORMAT)
+                    .and_then(|rest| rest.strip_prefix('='))
+                    .is_some_and(|formats| formats.split(',').any(|format| format.trim() == "json"))
+            })
+    }
+
+    /// Checks the raw command line for the argument, with or without a value, e.g. `--keyword` for `--keyword=slow`.
+    pub fn is_arg_requested(flag: &str) -> bool {
+        Self::arg_names(
+            godot::engine::Os::singleton()
+                .get_cmdline_user_args()
+                .as_slice()
+                .iter(),
+        )
+        .contains(flag)
+    }
+
+    fn arg_names<'a>(args: impl Iterator<Item = &'a GString>) -> BTreeSet<String> {
+        args.map(|arg| {
+            let arg = arg.to_string();
+            match arg.split_once('=') {
+                Some((name, _)) => name.to_owned(),
+                None => arg,
+            }
+        })
+        .collect()
+    }
+
+    /// Checks for `--exit-marker` without parsing the rest of arguments, to report errors in the arguments themselves.
+    pub fn is_exit_marker_requested() -> bool {
+        is_headless_run()
+            && godot::engine::Os::singleton()
+                .get_cmdline_user_args()
+                .as_slice()
+                .iter()
+                .any(|arg| arg.to_string() == Self::CMD_USER_EXIT_MARKER)
+    }
+
+    /// Gets `--status-file` without parsing the rest of arguments, so the status is written also on errors in the arguments
+    /// themselves. Relative path is resolved against `--artifacts-dir`, as in the parsed config.
+    pub fn requested_status_file() -> Option<String> {
+        let args = godot::engine::Os::singleton().get_cmdline_user_args();
+        let mut args_vec = args.as_slice().iter().collect::<Vec<_>>();
+        let status_file =
+            Self::get_all_args_with_value(&mut args_vec, Self::CMD_USER_STATUS_FILE).pop()?;
+        match Self::get_all_args_with_value(&mut args_vec, Self::CMD_USER_ARTIFACTS_DIR).pop() {
+            Some(dir) if Path::new(&status_file).is_relative() => Some(
+                Path::new(&dir)
+                    .join(&status_file)
+                    .to_string_lossy()
+                    .into_owned(),
+            ),
+            _ => Some(status_file),
+        }
+    }
+
+    /// Validates combinations of provided arguments.
+    ///
+    /// Mutually exclusive arguments are always rejected. Combinations which are allowed, but in which some argument is meaningless or
+    /// takes precedence over the other, are rejected only when `--strict-cli` is provided.
+    fn check_combinations(&self) -> Result<(), ConfigError> {
+        let mut errors = Vec::new();
+        errors.extend(
+            Self::check_mutually_exclusive_args(
+                self.allow_focus,
+                self.disallow_focus,
+                Self::CMD_USER_ALLOW_FOCUS,
+                Self::CMD_USER_DISALLOW_FOCUS,
+            )
+            .err(),
+        );
+        errors.extend(
+            Self::check_mutually_exclusive_args(
+                self.allow_skip,
+                self.disallow_skip,
+                Self::CMD_USER_ALLOW_SKIP,
+                Self::CMD_USER_DISALLOW_SKIP,
+            )
+            .err(),
+        );
+        errors.extend(
+            Self::check_mutually_exclusive_args(
+                self.mute_keyword,
+                !self.keyword.is_empty(),
+                Self::CMD_USER_MUTE_KEYWORD,
+                Self::CMD_USER_KEYWORD,
+            )
+            .err(),
+        );
+        errors.extend(
+            Self::check_mutually_exclusive_args(
+                self.mute_filters,
+                !self.filters.is_empty(),
+                Self::CMD_USER_MUTE_FILTERS,
+                Self::CMD_USER_FILTERS,
+            )
+            .err(),
+        );
+        errors.extend(
+            Self::check_mutually_exclusive_args(
+                self.mute_filters,
+                !self.filters_glob.is_empty(),
+                Self::CMD_USER_MUTE_FILTERS,
+                Self::CMD_USER_FILTERS_GLOB,
+            )
+            .err(),
+        );
+        errors.extend(
+            Self::check_mutually_exclusive_args(
+                self.only_leaf_failures,
+                self.report_formats.contains(&ReportFormat::Dots),
+                Self::CMD_USER_ONLY_LEAF_FAILURES,
+                "--format=dots",
+            )
+            .err(),
+        );
+        errors.extend(self.check_no_run_modes().err());
+        errors.extend(
+            Self::check_mutually_exclusive_args(
+                self.order == Some(CaseOrder::Defined),
+                self.shuffle,
+                "--order=defined",
+                Self::CMD_USER_SHUFFLE,
+            )
+            .err(),
+        );
+
+        if (self.bench_warn_pct.is_some() || self.bench_fail_pct.is_some())
+            && self.bench_baseline.is_none()
+        {
+            errors.push(ConfigError::of_kind(
+                ConfigErrorKind::MissingArg,
+                &[Self::CMD_USER_BENCH_WARN_PCT, Self::CMD_USER_BENCH_FAIL_PCT],
+                format!(
+                    "command line arguments {} and {} require {}",
+                    Self::CMD_USER_BENCH_WARN_PCT,
+                    Self::CMD_USER_BENCH_FAIL_PCT,
+                    Self::CMD_USER_BENCH_BASELINE
+                ),
+            ));
+        }
+        if self.perf_regression_pct.is_some() && self.compare_report.is_none() {
+            errors.push(ConfigError::of_kind(
+                ConfigErrorKind::MissingArg,
+                &[Self::CMD_USER_PERF_REGRESSION_PCT],
+                format!(
+                    "command line argument {} requires {}",
+                    Self::CMD_USER_PERF_REGRESSION_PCT,
+                    Self::CMD_USER_COMPARE_REPORT
+                ),
+            ));
+        }
+        if let (Some(warn), Some(fail)) = (self.bench_warn_pct, self.bench_fail_pct) {
+            if warn > fail {
+                errors.push(ConfigError::of_kind(
+                    ConfigErrorKind::InvalidValue,
+                    &[Self::CMD_USER_BENCH_WARN_PCT, Self::CMD_USER_BENCH_FAIL_PCT],
+                    format!(
+                        "{}={} needs to be lower or equal to {}={}",
+                        Self::CMD_USER_BENCH_WARN_PCT,
+                        warn,
+                        Self::CMD_USER_BENCH_FAIL_PCT,
+                        fail
+                    ),
+                ));
+            }
+        }
+
+        if !self.strict_cli {
+            return ConfigError::combine(errors);
+        }
+
+        errors.extend(
+            Self::check_ambiguous_args(
+                self.ignore_keywords,
+                !self.keyword.is_empty(),
+                Self::CMD_USER_IGNORE_KEYWORDS,
+                Self::CMD_USER_KEYWORD,
+            )
+            .err(),
+        );
+        errors.extend(
+            Self::check_ambiguous_args(
+                self.ignore_keywords,
+                self.mute_keyword,
+                Self::CMD_USER_IGNORE_KEYWORDS,
+                Self::CMD_USER_MUTE_KEYWORD,
+            )
+            .err(),
+        );
+        errors.extend(
+            Self::check_ambiguous_args(
+                self.description_filters_mode.is_some(),
+                self.description_filters.is_empty(),
+                Self::CMD_USER_FILTER_DESC_MODE,
+                &format!("no {}", Self::CMD_USER_FILTER_DESC),
+            )
+            .err(),
+        );
+
+        ConfigError::combine(errors)
+    }
+
+    fn parse_banner_fill(value: Option<String>) -> Result<Option<char>, ConfigError> {
+        let Some(value) = value else {
+            return Ok(None);
+        };
+        let mut chars = value.chars();
+        match (chars.next(), chars.next()) {
+            (Some(fill), None) => Ok(Some(fill)),
+            _ => Err(ConfigError::of_kind(
+                ConfigErrorKind::InvalidValue,
+                &[Self::CMD_USER_BANNER_FILL],
+                format!(
+                    "{} needs to be a single character, got: '{value}'",
+                    Self::CMD_USER_BANNER_FILL
+                ),
+            )),
+        }
+    }
+
+    fn parse_seed(value: &str, source: &str) -> Result<u64, ConfigError> {
+        value.trim().parse::<u64>().map_err(|_| {
+            ConfigError::of_kind(
+                ConfigErrorKind::InvalidValue,
+                &[source],
+                format!("{source} needs to be a non-negative integer, got: '{value}'"),
+            )
+        })
+    }
+
+    fn parse_seconds(values: Vec<String>, arg: &str) -> Result<Option<f64>, ConfigError> {
+        let Some(value) = values.first() else {
+            return Ok(None);
+        };
+        match value.parse::<f64>() {
+            Ok(secs) if secs >= 0.0 => Ok(Some(secs)),
+            _ => Err(ConfigError::of_kind(
+                ConfigErrorKind::InvalidValue,
+                &[arg],
+                format!("{arg} needs to be a non-negative number of seconds, got: '{value}'"),
+            )),
+        }
+    }
+
+    fn parse_priority_threshold(values: Vec<String>) -> Result<Option<i32>, ConfigError> {
+        let Some(value) = values.first() else {
+            return Ok(None);
+        };
+        value.parse::<i32>().map(Some).map_err(|_| {
+            ConfigError::of_kind(
+                ConfigErrorKind::InvalidValue,
+                &[Self::CMD_USER_PRIORITY_THRESHOLD],
+                format!(
+                    "{} needs to be an integer, got: '{}'",
+                    Self::CMD_USER_PRIORITY_THRESHOLD,
+                    value
+                ),
+            )
+        })
+    }
+
+    fn parse_shard(values: Vec<String>) -> Result<Option<(u64, u64)>, ConfigError> {
+        let Some(value) = values.first() else {
+            return Ok(None);
+        };
+        let shard = value.split_once('/').and_then(|(index, count)| {
+            Some((index.parse::<u64>().ok()?, count.parse::<u64>().ok()?))
+        });
+        match shard {
+            Some((index, count)) if (1..=count).contains(&index) => Ok(Some((index, count))),
+            _ => Err(ConfigError::of_kind(
+                ConfigErrorKind::InvalidValue,
+                &[Self::CMD_USER_SHARD],
+                format!(
+                    "{} needs to be in 'index/count' form with 1 <= index <= count, got: '{}'",
+                    Self::CMD_USER_SHARD,
+                    value
+                ),
+            )),
+        }
+    }
+
+    fn parse_globs(values: Vec<String>, arg: &str) -> Result<Vec<GlobPattern>, ConfigError> {
+        values
+            .iter()
+            .map(|value| {
+                GlobPattern::parse(value).map_err(|err| {
+                    ConfigError::of_kind(
+                        ConfigErrorKind::InvalidValue,
+                        &[arg],
+                        format!("{arg}: {err}"),
+                    )
+                })
+            })
+            .collect()
+    }
+
+    fn parse_report_formats(values: Vec<String>) -> Result<Vec<ReportFormat>, ConfigError> {
+        let mut formats = Vec::with_capacity(values.len());
+        for value in values {
+            let Some(format) = ReportFormat::from_arg(&value) else {
+                return Err(ConfigError::of_kind(
+                    ConfigErrorKind::InvalidValue,
+                    &[Self::CMD_USER_FORMAT],
+                    format!(
+                        "{} needs to be one of `json`, `junit` or `dots`, got: '{}'",
+                        Self::CMD_USER_FORMAT,
+                        value
+                    ),
+                ));
+            };
+            if !formats.contains(&format) {
+                formats.push(format);
+            }
+        }
+        Ok(formats)
+    }
+
+    fn parse_filters_match(values: Vec<String>) -> Result<Option<FiltersCombination>, ConfigError> {
+        let Some(value) = values.first() else {
+            return Ok(None);
+        };
+        FiltersCombination::from_match_arg(value)
+            .map(Some)
+            .ok_or_else(|| {
+                ConfigError::of_kind(
+                    ConfigErrorKind::InvalidValue,
+                    &[Self::CMD_USER_FILTERS_MODE],
+                    format!(
+                        "{} needs to be either `any` or `all`, got: '{}'",
+                        Self::CMD_USER_FILTERS_MODE,
+                        value
+                    ),
+                )
+            })
+    }
+
+    fn parse_group_by(values: Vec<String>) -> Result<Option<GroupBy>, ConfigError> {
+        let Some(value) = values.first() else {
+            return Ok(None);
+        };
+        GroupBy::from_arg(value).map(Some).ok_or_else(|| {
+            ConfigError::of_kind(
+                ConfigErrorKind::InvalidValue,
+                &[Self::CMD_USER_GROUP_BY],
+                format!(
+                    "{} needs to be one of `order`, `outcome` or `suite`, got: '{}'",
+                    Self::CMD_USER_GROUP_BY,
+                    value
+                ),
+            )
         })
     }
 
+    fn parse_retry_on(values: Vec<String>) -> Result<Vec<FailureKind>, ConfigError> {
+        let mut kinds = Vec::with_capacity(values.len());
+        for value in values {
+            let Some(kind) = FailureKind::from_arg(&value) else {
+                return Err(ConfigError::of_kind(
+                    ConfigErrorKind::InvalidValue,
+                    &[Self::CMD_USER_RETRY_ON],
+                    format!(
+                        "{} needs to be a list of `assertion`, `panic`, `setup`, `cleanup`, `leak`, `regression` or `timeout`, got: '{}'",
+                        Self::CMD_USER_RETRY_ON,
+                        value
+                    ),
+                ));
+            };
+            if !kinds.contains(&kind) {
+                kinds.push(kind);
+            }
+        }
+        Ok(kinds)
+    }
+
+    fn parse_order(values: Vec<String>) -> Result<Option<CaseOrder>, ConfigError> {
+        let Some(value) = values.first() else {
+            return Ok(None);
+        };
+        CaseOrder::from_arg(value).map(Some).ok_or_else(|| {
+            ConfigError::of_kind(
+                ConfigErrorKind::InvalidValue,
+                &[Self::CMD_USER_ORDER],
+                format!(
+                    "{} needs to be one of `sorted` or `defined`, got: '{}'",
+                    Self::CMD_USER_ORDER,
+                    value
+                ),
+            )
+        })
+    }
+
+    fn parse_time_unit(values: Vec<String>) -> Result<Option<TimeUnit>, ConfigError> {
+        let Some(value) = values.first() else {
+            return Ok(None);
+        };
+        TimeUnit::from_arg(value).map(Some).ok_or_else(|| {
+            ConfigError::of_kind(
+                ConfigErrorKind::InvalidValue,
+                &[Self::CMD_USER_TIME_UNIT],
+                format!(
+                    "{} needs to be one of `auto`, `s`, `ms`, `us` or `ns`, got: '{}'",
+                    Self::CMD_USER_TIME_UNIT,
+                    value
+                ),
+            )
+        })
+    }
+
+    fn parse_panic_detail(values: Vec<String>) -> Result<Option<PanicDetail>, ConfigError> {
+        let Some(value) = values.first() else {
+            return Ok(None);
+        };
+        PanicDetail::from_arg(value).map(Some).ok_or_else(|| {
+            ConfigError::of_kind(
+                ConfigErrorKind::InvalidValue,
+                &[Self::CMD_USER_PANIC_DETAIL],
+                format!(
+                    "{} needs to be one of `minimal`, `message` or `backtrace`, got: '{}'",
+                    Self::CMD_USER_PANIC_DETAIL,
+                    value
+                ),
+            )
+        })
+    }
+
+    fn parse_filters_combination(
+        values: Vec<String>,
+    ) -> Result<Option<FiltersCombination>, ConfigError> {
+        let Some(value) = values.first() else {
+            return Ok(None);
+        };
+        FiltersCombination::from_arg(value)
+            .map(Some)
+            .ok_or_else(|| {
+                ConfigError::of_kind(
+                    ConfigErrorKind::InvalidValue,
+                    &[Self::CMD_USER_FILTER_DESC_MODE],
+                    format!(
+                        "{} needs to be either `and` or `or`, got: '{}'",
+                        Self::CMD_USER_FILTER_DESC_MODE,
+                        value
+                    ),
+                )
+            })
+    }
+
+    /// Parses non-negative percentage, optionally limited by `max`.
+    fn parse_percentage(
+        values: Vec<String>,
+        arg: &str,
+        max: Option<f64>,
+    ) -> Result<Option<f64>, ConfigError> {
+        let Some(value) = values.first() else {
+            return Ok(None);
+        };
+        match value.parse::<f64>() {
+            Ok(pct) if pct >= 0.0 && max.is_none_or(|max| pct <= max) => Ok(Some(pct)),
+            _ => Err(ConfigError::of_kind(
+                ConfigErrorKind::InvalidValue,
+                &[arg],
+                match max {
+                    Some(max) => format!(
+                        "{} needs to be a percentage between 0 and {}, got: '{}'",
+                        arg, max, value
+                    ),
+                    None => format!(
+                        "{} needs to be a non-negative percentage, got: '{}'",
+                        arg, value
+                    ),
+                },
+            )),
+        }
+    }
+
+    fn parse_meta(values: Vec<String>) -> Result<Vec<(String, String)>, ConfigError> {
+        let mut meta: Vec<(String, String)> = Vec::with_capacity(values.len());
+        for value in values {
+            let Some((key, val)) = value.split_once('=') else {
+                return Err(ConfigError::of_kind(
+                    ConfigErrorKind::InvalidValue,
+                    &[Self::CMD_USER_META],
+                    format!(
+                        "{} needs to be specified as `key=value`, got: '{}'",
+                        Self::CMD_USER_META,
+                        value
+                    ),
+                ));
+            };
+            if key.is_empty() {
+                return Err(ConfigError::of_kind(
+                    ConfigErrorKind::InvalidValue,
+                    &[Self::CMD_USER_META],
+                    format!(
+                        "{} key cannot be empty, got: '{}'",
+                        Self::CMD_USER_META,
+                        value
+                    ),
+                ));
+            }
+            if meta.iter().any(|(existing, _)| existing == key) {
+                return Err(ConfigError::of_kind(
+                    ConfigErrorKind::InvalidValue,
+                    &[Self::CMD_USER_META],
+                    format!(
+                        "{} key '{}' provided more than once",
+                        Self::CMD_USER_META,
+                        key
+                    ),
+                ));
+            }
+            meta.push((key.to_owned(), val.to_owned()));
+        }
+        Ok(meta)
+    }
+
+    /// Loads test names, one per line as `test_name` or `file.rs::test_name`. Empty lines and lines starting with `#` are skipped.
+    fn load_order_file(path: &str) -> Result<Vec<String>, ConfigError> {
+        let content = std::fs::read_to_string(path).map_err(|err| {
+            ConfigError::of_kind(
+                ConfigErrorKind::File,
+                &[Self::CMD_USER_ORDER_FILE],
+                format!(
+                    "{} couldn't read '{path}': {err}",
+                    Self::CMD_USER_ORDER_FILE
+                ),
+            )
+        })?;
+        Ok(content
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .map(str::to_owned)
+            .collect())
+    }
+
+    /// Loads metadata from either JSON object or `key=value` lines, skipping empty ones and `#` comments.
+    fn load_meta_file(path: &str) -> Result<Vec<(String, String)>, ConfigError> {
+        let content = std::fs::read_to_string(path).map_err(|err| {
+            ConfigError::of_kind(
+                ConfigErrorKind::File,
+                &[Self::CMD_USER_META_FILE],
+                format!("{} couldn't read '{path}': {err}", Self::CMD_USER_META_FILE),
+            )
+        })?;
+
+        if content.trim_start().starts_with('{') {
+            let object =
+                serde_json::from_str::<serde_json::Map<String, serde_json::Value>>(&content)
+                    .map_err(|err| {
+                        ConfigError::of_kind(
+                            ConfigErrorKind::File,
+                            &[Self::CMD_USER_META_FILE],
+                            format!(
+                                "{} malformed JSON in '{path}': {err}",
+                                Self::CMD_USER_META_FILE
+                            ),
+                        )
+                    })?;
+            return Ok(object
+                .into_iter()
+                .map(|(key, value)| match value {
+                    serde_json::Value::String(value) => (key, value),
+                    value => (key, value.to_string()),
+                })
+                .collect());
+        }
+
+        let mut meta: Vec<(String, String)> = Vec::new();
+        for (i, line) in content.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            match line.split_once('=') {
+                Some((key, value)) if !key.trim().is_empty() => {
+                    let key = key.trim();
+                    meta.retain(|(existing, _)| existing != key);
+                    meta.push((key.to_owned(), value.trim().to_owned()));
+                }
+                _ => {
+                    return Err(ConfigError::of_kind(
+                        ConfigErrorKind::File,
+                        &[Self::CMD_USER_META_FILE],
+                        format!(
+                            "{} malformed line {} in '{path}', expected `key=value`: '{line}'",
+                            Self::CMD_USER_META_FILE,
+                            i + 1
+                        ),
+                    ))
+                }
+            }
+        }
+        Ok(meta)
+    }
+
+    /// Gets values of the declared custom arguments which were provided, with empty values for flags.
+    fn parse_custom_args(
+        args: &mut Vec<&GString>,
+        custom_args: &[CustomArg],
+    ) -> Result<Vec<(String, String)>, ConfigError> {
+        let mut values = Vec::new();
+        for arg in custom_args {
+            if !arg.name.starts_with("--") {
+                return Err(ConfigError::new(format!(
+                    "custom argument '{}' needs to start with '--'",
+                    arg.name
+                )));
+            }
+            if arg.takes_value {
+                if let Some(value) = Self::get_all_args_with_value(args, &arg.name).pop() {
+                    values.push((arg.name.clone(), value));
+                }
+            } else if Self::get_arg(args, arg.name.as_str()) {
+                values.push((arg.name.clone(), String::new()));
+            }
+        }
+        Ok(values)
+    }
+
     fn check_unrecognized_args(unrecognized_args: &Vec<String>) -> Result<(), ConfigError> {
         if unrecognized_args.is_empty() {
             return Ok(());
         }
-        Err(ConfigError::new(format!(
-            "unrecognized args provided: {:#?}",
-            unrecognized_args
-        )))
+        Err(ConfigError::of_kind(
+            ConfigErrorKind::UnrecognizedArgs,
+            &unrecognized_args
+                .iter()
+                .map(String::as_str)
+                .collect::<Vec<_>>(),
+            format!("unrecognized args provided: {:#?}", unrecognized_args),
+        ))
     }
 
     fn check_mutually_exclusive_args(
@@ -155,14 +1551,82 @@ impl CliConfig {
         arg_2: &str,
     ) -> Result<(), ConfigError> {
         match (arg_1_val, arg_2_val) {
-            (true, true) => Err(ConfigError::new(format!(
-                "command line arguments {} and {} are mutually exclusive",
-                arg_1, arg_2
-            ))),
+            (true, true) => Err(ConfigError::of_kind(
+                ConfigErrorKind::ConflictingArgs,
+                &[arg_1, arg_2],
+                format!(
+                    "command line arguments {} and {} are mutually exclusive",
+                    arg_1, arg_2
+                ),
+            )),
+            _ => Ok(()),
+        }
+    }
+
+    /// Rejects combinations of the modes which don't run any case, as each of them replaces the run with different output.
+    ///
+    /// `--no-run` stops after the cases are selected, so it's implied by the listing modes and allowed together with them.
+    /// `--check-config`, `--render-report`, `--merge-reports` and `--describe` stop before the selection, so they can't be
+    /// combined with it.
+    fn check_no_run_modes(&self) -> Result<(), ConfigError> {
+        let standalone = self.render_report.is_some()
+            || !self.merge_reports.is_empty()
+            || self.describe.is_some()
+            || self.check_config;
+        let active = [
+            (self.list_json, Self::CMD_USER_LIST_JSON),
+            (self.list_suites, Self::CMD_USER_LIST_SUITES),
+            (self.render_report.is_some(), Self::CMD_USER_RENDER_REPORT),
+            (!self.merge_reports.is_empty(), Self::CMD_USER_MERGE_REPORTS),
+            (self.describe.is_some(), Self::CMD_USER_DESCRIBE),
+            (self.check_config, Self::CMD_USER_CHECK_CONFIG),
+            (self.no_run && standalone, Self::CMD_USER_NO_RUN),
+        ]
+        .into_iter()
+        .filter_map(|(is_active, arg)| is_active.then_some(arg))
+        .collect::<Vec<_>>();
+        if active.len() < 2 {
+            return Ok(());
+        }
+        Err(ConfigError::of_kind(
+            ConfigErrorKind::ConflictingArgs,
+            &active,
+            format!(
+                "command line arguments {} can't be combined, as each of them replaces the run differently",
+                active.join(", ")
+            ),
+        ))
+    }
+
+    fn check_ambiguous_args(
+        arg_1_val: bool,
+        arg_2_val: bool,
+        arg_1: &str,
+        arg_2: &str,
+    ) -> Result<(), ConfigError> {
+        match (arg_1_val, arg_2_val) {
+            (true, true) => Err(ConfigError::of_kind(
+                ConfigErrorKind::ConflictingArgs,
+                &[arg_1, arg_2],
+                format!(
+                    "command line arguments {} and {} are ambiguous when used together with {}",
+                    arg_1,
+                    arg_2,
+                    Self::CMD_USER_STRICT_CLI
+                ),
+            )),
             _ => Ok(()),
         }
     }
 
+    /// Returns the parsed value, or the default one after storing the error.
+    fn collect<T: Default>(errors: &mut Vec<ConfigError>, result: Result<T, ConfigError>) -> T {
+        result.unwrap_or_else(|error| {
+            errors.push(error);
+            T::default()
+        })
+    }
+
     fn get_arg(args: &mut Vec<&GString>, get_arg: impl Into<GString>) -> bool {
         let mut gotten = false;
         let get_arg: GString = get_arg.into();
@@ -177,26 +1641,44 @@ impl CliConfig {
         gotten
     }
 
+    /// Gets comma-separated values of the first `name=value` argument. Only exact `name` matches, so arguments sharing a prefix
+    /// (e.g. `--filters` and `--filters-mode`) don't consume each other. `name` without a value yields no values.
     fn get_arg_with_value(args: &mut Vec<&GString>, get_arg: &str) -> Vec<String> {
         for (i, arg) in args.iter_mut().enumerate() {
-            let cur_arg = arg.clone();
-            let arg_str = cur_arg.to_string();
-            if arg_str.starts_with(get_arg) {
-                let values = arg_str.split('=').collect::<Vec<_>>()[1];
-                let values = values
+            let arg_str = arg.to_string();
+            let values = match arg_str.split_once('=') {
+                Some((name, values)) if name == get_arg => values
                     .split(',')
                     .map(|str| str.to_owned())
-                    .collect::<Vec<String>>();
-                args.remove(i);
-                return values;
-            }
+                    .collect::<Vec<String>>(),
+                None if arg_str == get_arg => Vec::new(),
+                _ => continue,
+            };
+            args.remove(i);
+            return values;
         }
         Vec::new()
     }
+
+    fn get_all_args_with_value(args: &mut Vec<&GString>, get_arg: &str) -> Vec<String> {
+        let prefix = format!("{get_arg}=");
+        let mut values = Vec::new();
+        args.retain(|arg| {
+            let arg_str = arg.to_string();
+            if let Some(value) = arg_str.strip_prefix(&prefix) {
+                values.push(value.to_owned());
+                return false;
+            }
+            true
+        });
+        values
+    }
 }
 
+/// Configuration of the run, merged from properties of the [`GdTestRunner`](super::GdTestRunner), command line arguments
+/// and environment variables. Created with [`RunnerConfig::builder`].
 #[derive(Default)]
-pub(crate) struct RunnerConfig {
+pub struct RunnerConfig {
     disallow_focus: bool,
     disallow_skip: bool,
     run_rust_tests: bool,
@@ -206,10 +1688,86 @@ pub(crate) struct RunnerConfig {
     only_scene_path: bool,
     scene_path: String,
     filters: Vec<String>,
+    filters_glob: Vec<GlobPattern>,
+    exclude_glob: Vec<GlobPattern>,
+    filters_mode: FiltersCombination,
+    group_by: GroupBy,
+    order: CaseOrder,
+    order_file: Vec<String>,
     quiet_run: bool,
+    auto_verbosity: bool,
+    always_summary: bool,
+    meta: Vec<(String, String)>,
+    min_pass_rate: Option<f64>,
+    description_filters: Vec<String>,
+    description_filters_mode: FiltersCombination,
+    summary_file: Option<String>,
+    priority_threshold: Option<i32>,
+    shard: Option<(u64, u64)>,
+    bench_baseline: Option<String>,
+    bench_save_baseline: Option<String>,
+    compare_report: Option<String>,
+    render_report: Option<String>,
+    merge_reports: Vec<String>,
+    describe: Option<String>,
+    bench_warn_pct: Option<f64>,
+    bench_fail_pct: Option<f64>,
+    perf_regression_pct: Option<f64>,
+    report_formats: Vec<ReportFormat>,
+    json_report: Option<String>,
+    junit_report: Option<String>,
+    csv_report: Option<String>,
+    status_file: Option<String>,
+    artifacts_dir: Option<String>,
+    no_banner: bool,
+    timeout: Option<f64>,
+    timeout_warn: Option<f64>,
+    suite_timeout: Option<f64>,
+    bench_timeout: Option<f64>,
+    max_total_fail: Option<f64>,
+    interactive: bool,
+    run_seed: (u64, SeedSource),
+    shuffle: bool,
+    exit_marker: bool,
+    retries: u32,
+    retry_on: Vec<FailureKind>,
+    list_json: bool,
+    list_suites: bool,
+    forbid_focus: bool,
+    fail_empty: bool,
+    require_services: bool,
+    update_snapshots: bool,
+    max_message_len: usize,
+    percent_precision: usize,
+    ignored_args: Vec<String>,
+    bench_histogram: bool,
+    bench_group_relative: bool,
+    bench_filters: Vec<String>,
+    suites: Vec<String>,
+    stall_timeout: Option<Duration>,
+    banner_fill: char,
+    no_signal_handler: bool,
+    only_leaf_failures: bool,
+    sort_output: bool,
+    fail_on_mode_skip: bool,
+    strict_selection: bool,
+    smoke: bool,
+    check_config: bool,
+    time_unit: Option<TimeUnit>,
+    no_run: bool,
+    run_ignored: bool,
+    panic_detail: PanicDetail,
+    relative_paths: Option<PathBuf>,
+    custom_args: Arc<Vec<(String, String)>>,
+    /// Names of the built-in command line arguments which were provided.
+    provided: BTreeSet<String>,
+    overrides: Vec<String>,
 }
 
 impl RunnerConfig {
+    /// Environment variable with the `--shuffle` seed, used if `--seed` isn't provided.
+    pub const ENV_SEED: &'static str = "GODOT_TEST_SEED";
+
     pub fn disallow_focus(&self) -> bool {
         self.disallow_focus
     }
@@ -230,6 +1788,31 @@ impl RunnerConfig {
         &self.filters
     }
 
+    pub(crate) fn filters_glob(&self) -> &[GlobPattern] {
+        &self.filters_glob
+    }
+
+    pub(crate) fn exclude_glob(&self) -> &[GlobPattern] {
+        &self.exclude_glob
+    }
+
+    pub(crate) fn filters_mode(&self) -> FiltersCombination {
+        self.filters_mode
+    }
+
+    pub(crate) fn group_by(&self) -> GroupBy {
+        self.group_by
+    }
+
+    pub(crate) fn order(&self) -> CaseOrder {
+        self.order
+    }
+
+    /// Names of tests loaded with `--order-file`, to be executed first in that order.
+    pub fn order_file(&self) -> &[String] {
+        &self.order_file
+    }
+
     pub fn run_rust_tests(&self) -> bool {
         self.run_rust_tests
     }
@@ -250,6 +1833,328 @@ impl RunnerConfig {
         self.quiet_run
     }
 
+    /// Hold back the output until the end of the run, printing it whole only if the run failed. Has no effect with `--quiet-run`.
+    pub fn auto_verbosity(&self) -> bool {
+        self.auto_verbosity && !self.quiet_run
+    }
+
+    /// Print the counts of the whole run after all reporters, regardless of the other output options.
+    pub fn always_summary(&self) -> bool {
+        self.always_summary
+    }
+
+    pub fn meta(&self) -> &[(String, String)] {
+        &self.meta
+    }
+
+    pub fn min_pass_rate(&self) -> Option<f64> {
+        self.min_pass_rate
+    }
+
+    pub fn description_filters(&self) -> &Vec<String> {
+        &self.description_filters
+    }
+
+    pub(crate) fn description_filters_mode(&self) -> FiltersCombination {
+        self.description_filters_mode
+    }
+
+    pub fn summary_file(&self) -> Option<&str> {
+        self.summary_file.as_deref()
+    }
+
+    pub fn priority_threshold(&self) -> Option<i32> {
+        self.priority_threshold
+    }
+
+    /// Shard of the cases to run, as `(index, count)` with index starting at 1.
+    pub fn shard(&self) -> Option<(u64, u64)> {
+        self.shard
+    }
+
+    pub fn bench_baseline(&self) -> Option<&str> {
+        self.bench_baseline.as_deref()
+    }
+
+    /// Path to the JSON report of previous run, to compare the outcomes against.
+    pub fn compare_report(&self) -> Option<&str> {
+        self.compare_report.as_deref()
+    }
+
+    pub fn render_report(&self) -> Option<&str> {
+        self.render_report.as_deref()
+    }
+
+    /// Paths or glob patterns of the JSON reports of sharded runs, to merge instead of running the cases.
+    pub fn merge_reports(&self) -> &[String] {
+        &self.merge_reports
+    }
+
+    /// Name of the test to describe instead of running the cases, as `test_name` or `file.rs::test_name`.
+    pub fn describe(&self) -> Option<&str> {
+        self.describe.as_deref()
+    }
+
+    pub fn bench_save_baseline(&self) -> Option<&str> {
+        self.bench_save_baseline.as_deref()
+    }
+
+    pub fn bench_warn_pct(&self) -> Option<f64> {
+        self.bench_warn_pct
+    }
+
+    pub fn bench_fail_pct(&self) -> Option<f64> {
+        self.bench_fail_pct
+    }
+
+    /// Threshold for tests slower than in the `--compare-report` report, to warn about.
+    pub fn perf_regression_pct(&self) -> Option<f64> {
+        self.perf_regression_pct
+    }
+
+    pub(crate) fn report_formats(&self) -> &[ReportFormat] {
+        &self.report_formats
+    }
+
+    /// Whether any report is printed to stdout, which then needs to be kept free of the human-readable output.
+    pub fn prints_reports_to_stdout(&self) -> bool {
+        self.report_formats
+            .iter()
+            .any(|format| matches!(format, ReportFormat::Json | ReportFormat::Junit))
+    }
+
+    pub fn json_report(&self) -> Option<&str> {
+        self.json_report.as_deref()
+    }
+
+    pub fn junit_report(&self) -> Option<&str> {
+        self.junit_report.as_deref()
+    }
+
+    pub fn csv_report(&self) -> Option<&str> {
+        self.csv_report.as_deref()
+    }
+
+    /// Path of the file with just the status and counts of the run, for badge generators.
+    pub fn status_file(&self) -> Option<&str> {
+        self.status_file.as_deref()
+    }
+
+    pub fn artifacts_dir(&self) -> Option<&str> {
+        self.artifacts_dir.as_deref()
+    }
+
+    pub fn no_banner(&self) -> bool {
+        self.no_banner
+    }
+
+    /// Default timeout of tests in seconds. `0` means no timeout.
+    pub fn timeout(&self) -> Option<f64> {
+        self.timeout
+    }
+
+    /// Budget of all tests of every suite in seconds, for suites not declaring their own one. `0` means no budget.
+    pub fn suite_timeout(&self) -> Option<f64> {
+        self.suite_timeout
+    }
+
+    /// Soft budget of tests in seconds, passed tests exceeding it are warned about. `0` means no warning.
+    pub fn timeout_warn(&self) -> Option<f64> {
+        self.timeout_warn
+    }
+
+    /// Timeout of every benchmark in seconds, separate from the test one. `0` means no timeout.
+    pub fn bench_timeout(&self) -> Option<f64> {
+        self.bench_timeout
+    }
+
+    /// Budget of the whole run in seconds. The run isn't stopped after exceeding it, but fails once finished.
+    pub fn max_total_fail(&self) -> Option<f64> {
+        self.max_total_fail
+    }
+
+    pub fn interactive(&self) -> bool {
+        self.interactive
+    }
+
+    /// Seed of the run, from which seeds of all tests are derived.
+    pub(crate) fn run_seed(&self) -> (u64, SeedSource) {
+        self.run_seed
+    }
+
+    /// Seed for shuffling the cases, if `--shuffle` is on.
+    pub(crate) fn shuffle_seed(&self) -> Option<(u64, SeedSource)> {
+        self.shuffle.then_some(self.run_seed)
+    }
+
+    pub fn exit_marker(&self) -> bool {
+        self.exit_marker
+    }
+
+    /// Default count of retries for failed tests.
+    pub fn retries(&self) -> u32 {
+        self.retries
+    }
+
+    /// Kinds of failures which are retried. All of them are if empty.
+    pub fn retry_on(&self) -> &[FailureKind] {
+        &self.retry_on
+    }
+
+    /// Only list the selected cases as JSON, without running them.
+    pub fn list_json(&self) -> bool {
+        self.list_json
+    }
+
+    /// Only list the suites of selected cases, without running them.
+    pub fn list_suites(&self) -> bool {
+        self.list_suites
+    }
+
+    /// Whether the run only lists the selected cases in any form.
+    pub fn is_listing(&self) -> bool {
+        self.list_json || self.list_suites
+    }
+
+    /// Fail the run if any case is focused.
+    pub fn forbid_focus(&self) -> bool {
+        self.forbid_focus
+    }
+
+    /// Fail the run if no case is registered at all, regardless of filtering.
+    pub fn fail_empty(&self) -> bool {
+        self.fail_empty
+    }
+
+    /// Fail tests calling `require_service` with unreachable service, instead of skipping them.
+    pub fn require_services(&self) -> bool {
+        self.require_services
+    }
+
+    /// Save the actual resources of `assert_resource_matches!` to the compared files, instead of comparing them.
+    pub fn update_snapshots(&self) -> bool {
+        self.update_snapshots
+    }
+
+    /// Length to which failure messages printed to the console are truncated. `0` means no truncation.
+    pub fn max_message_len(&self) -> usize {
+        self.max_message_len
+    }
+
+    /// Number of decimal places of percentages printed in the run output.
+    pub fn percent_precision(&self) -> usize {
+        self.percent_precision
+    }
+
+    /// Unrecognized arguments ignored because of `--ignore-unknown-args`.
+    pub fn ignored_args(&self) -> &[String] {
+        &self.ignored_args
+    }
+
+    pub fn bench_histogram(&self) -> bool {
+        self.bench_histogram
+    }
+
+    pub fn bench_group_relative(&self) -> bool {
+        self.bench_group_relative
+    }
+
+    /// Name filters for benchmarks. If not empty, they replace the name filters for benchmarks.
+    pub fn bench_filters(&self) -> &[String] {
+        &self.bench_filters
+    }
+
+    pub fn suites(&self) -> &[String] {
+        &self.suites
+    }
+
+    /// Interval after which the currently running case is reported, if no case finished in the meantime.
+    pub fn stall_timeout(&self) -> Option<Duration> {
+        self.stall_timeout
+    }
+
+    /// Character padding the centered run information.
+    pub fn banner_fill(&self) -> char {
+        self.banner_fill
+    }
+
+    /// Don't install the `SIGINT` handler stopping the run between cases.
+    pub fn no_signal_handler(&self) -> bool {
+        self.no_signal_handler
+    }
+
+    pub fn only_leaf_failures(&self) -> bool {
+        self.only_leaf_failures
+    }
+
+    pub fn sort_output(&self) -> bool {
+        self.sort_output
+    }
+
+    pub fn fail_on_mode_skip(&self) -> bool {
+        self.fail_on_mode_skip
+    }
+
+    /// Fail the run on suspicious keyword selection instead of noting it.
+    pub fn strict_selection(&self) -> bool {
+        self.strict_selection
+    }
+
+    /// Only tests marked with `smoke` should be executed.
+    pub fn smoke(&self) -> bool {
+        self.smoke
+    }
+
+    /// Only validate the config and print it, without running any case.
+    pub fn check_config(&self) -> bool {
+        self.check_config
+    }
+
+    /// Unit of durations in the human-readable output, if overridden.
+    pub(crate) fn time_unit(&self) -> Option<TimeUnit> {
+        self.time_unit
+    }
+
+    /// Stop after selecting the cases, without running them. Implied by the listing modes.
+    pub fn no_run(&self) -> bool {
+        self.no_run || self.is_listing()
+    }
+
+    /// Run also the tests from ignored suites.
+    pub fn run_ignored(&self) -> bool {
+        self.run_ignored
+    }
+
+    pub(crate) fn panic_detail(&self) -> PanicDetail {
+        self.panic_detail
+    }
+
+    /// Root against which source paths in reports and messages are made relative.
+    pub fn relative_paths(&self) -> Option<&Path> {
+        self.relative_paths.as_deref()
+    }
+
+    /// Provided custom arguments declared by the embedder, as `(name, value)`. Flags have empty values.
+    pub fn custom_args(&self) -> &Arc<Vec<(String, String)>> {
+        &self.custom_args
+    }
+
+    /// Whether the built-in command line argument, e.g. [`CliConfig::CMD_USER_KEYWORD`], was provided, as opposed to the value
+    /// being defaulted or taken from the properties of the runner.
+    pub fn is_provided(&self, flag: &str) -> bool {
+        self.provided.contains(flag)
+    }
+
+    /// Properties passed by the caller which were changed by command line arguments.
+    pub fn overrides(&self) -> &[String] {
+        &self.overrides
+    }
+
+    pub fn builder() -> RunnerConfigBuilder {
+        RunnerConfigBuilder::default()
+    }
+
+    /// Kept for backward compatibility, prefer [`RunnerConfig::builder`].
     #[allow(clippy::too_many_arguments)]
     pub fn new(
         disallow_focus: bool,
@@ -263,12 +2168,34 @@ impl RunnerConfig {
         filters: &PackedStringArray,
         quiet_run: bool,
     ) -> Result<Self, ConfigError> {
-        let keyword = keyword.to_string();
-        let filters = filters
-            .as_slice()
-            .iter()
-            .map(|str| str.to_string())
-            .collect::<Vec<_>>();
+        Self::builder()
+            .disallow_focus(disallow_focus)
+            .disallow_skip(disallow_skip)
+            .run_rust_tests(run_rust_tests)
+            .run_rust_benchmarks(run_rust_benchmarks)
+            .keyword(keyword.to_string())
+            .ignore_keywords(ignore_keywords)
+            .only_scene_path(only_scene_path)
+            .scene_path(scene_path)
+            .filters(filters.as_slice().iter().map(|str| str.to_string()))
+            .quiet_run(quiet_run)
+            .build()
+    }
+
+    fn from_builder(builder: RunnerConfigBuilder) -> Result<Self, ConfigError> {
+        let RunnerConfigBuilder {
+            disallow_focus,
+            disallow_skip,
+            run_rust_tests,
+            run_rust_benchmarks,
+            keyword,
+            ignore_keywords,
+            only_scene_path,
+            scene_path,
+            filters,
+            quiet_run,
+            custom_args,
+        } = builder;
 
         let mut instance = Self {
             disallow_focus,
@@ -280,58 +2207,455 @@ impl RunnerConfig {
             only_scene_path,
             scene_path,
             filters,
+            filters_glob: Vec::new(),
+            exclude_glob: Vec::new(),
+            filters_mode: FiltersCombination::Or,
+            group_by: GroupBy::default(),
+            order: CaseOrder::default(),
+            order_file: Vec::new(),
             quiet_run,
+            auto_verbosity: false,
+            always_summary: false,
+            meta: Vec::new(),
+            min_pass_rate: None,
+            description_filters: Vec::new(),
+            description_filters_mode: FiltersCombination::default(),
+            summary_file: None,
+            priority_threshold: None,
+            shard: None,
+            bench_baseline: None,
+            compare_report: None,
+            render_report: None,
+            merge_reports: Vec::new(),
+            describe: None,
+            bench_save_baseline: None,
+            bench_warn_pct: None,
+            bench_fail_pct: None,
+            perf_regression_pct: None,
+            report_formats: Vec::new(),
+            json_report: None,
+            junit_report: None,
+            csv_report: None,
+            status_file: None,
+            artifacts_dir: None,
+            no_banner: false,
+            timeout: None,
+            timeout_warn: None,
+            suite_timeout: None,
+            bench_timeout: None,
+            max_total_fail: None,
+            interactive: false,
+            run_seed: (0, SeedSource::Random),
+            shuffle: false,
+            exit_marker: false,
+            retries: 0,
+            retry_on: Vec::new(),
+            list_json: false,
+            list_suites: false,
+            forbid_focus: false,
+            fail_empty: false,
+            require_services: false,
+            update_snapshots: false,
+            max_message_len: 0,
+            percent_precision: 2,
+            ignored_args: Vec::new(),
+            bench_histogram: false,
+            bench_group_relative: false,
+            bench_filters: Vec::new(),
+            suites: Vec::new(),
+            stall_timeout: None,
+            banner_fill: ' ',
+            no_signal_handler: false,
+            only_leaf_failures: false,
+            sort_output: false,
+            fail_on_mode_skip: false,
+            strict_selection: false,
+            smoke: false,
+            check_config: false,
+            time_unit: None,
+            no_run: false,
+            run_ignored: false,
+            panic_detail: PanicDetail::default(),
+            relative_paths: None,
+            custom_args: Arc::default(),
+            provided: BTreeSet::new(),
+            overrides: Vec::new(),
         };
 
         if !is_headless_run() {
             return Ok(instance);
         }
 
-        let cmdline = CliConfig::from_os()?;
+        let cmdline = CliConfig::from_os(&custom_args)?;
+        // Like in `CliConfig::from_os`, all found errors are reported at once.
+        let mut errors = Vec::new();
 
+        let overrides = &mut instance.overrides;
         if cmdline.run_rust_tests || cmdline.run_rust_benchmarks {
-            instance.run_rust_tests = cmdline.run_rust_tests;
-            instance.run_rust_benchmarks = cmdline.run_rust_benchmarks;
+            let arg_tests = if cmdline.run_rust_tests {
+                CliConfig::CMD_USER_RUST_TESTS
+            } else {
+                CliConfig::CMD_USER_RUST_BENCHMARKS
+            };
+            let arg_benchmarks = if cmdline.run_rust_benchmarks {
+                CliConfig::CMD_USER_RUST_BENCHMARKS
+            } else {
+                CliConfig::CMD_USER_RUST_TESTS
+            };
+            apply_override(
+                overrides,
+                "run_rust_tests",
+                arg_tests,
+                &mut instance.run_rust_tests,
+                cmdline.run_rust_tests,
+            );
+            apply_override(
+                overrides,
+                "run_rust_benchmarks",
+                arg_benchmarks,
+                &mut instance.run_rust_benchmarks,
+                cmdline.run_rust_benchmarks,
+            );
         }
         if cmdline.allow_focus {
-            instance.disallow_focus = false
+            apply_override(
+                overrides,
+                "disallow_focus",
+                CliConfig::CMD_USER_ALLOW_FOCUS,
+                &mut instance.disallow_focus,
+                false,
+            );
         };
         if cmdline.disallow_focus {
-            instance.disallow_focus = true
+            apply_override(
+                overrides,
+                "disallow_focus",
+                CliConfig::CMD_USER_DISALLOW_FOCUS,
+                &mut instance.disallow_focus,
+                true,
+            );
         };
         if cmdline.allow_skip {
-            instance.disallow_skip = false
+            apply_override(
+                overrides,
+                "disallow_skip",
+                CliConfig::CMD_USER_ALLOW_SKIP,
+                &mut instance.disallow_skip,
+                false,
+            );
         };
         if cmdline.disallow_skip {
-            instance.disallow_skip = true
+            apply_override(
+                overrides,
+                "disallow_skip",
+                CliConfig::CMD_USER_DISALLOW_SKIP,
+                &mut instance.disallow_skip,
+                true,
+            );
         };
         if cmdline.mute_filters {
-            instance.filters = Vec::new()
+            apply_override(
+                overrides,
+                "filters",
+                CliConfig::CMD_USER_MUTE_FILTERS,
+                &mut instance.filters,
+                Vec::new(),
+            );
         };
         if !cmdline.filters.is_empty() {
-            instance.filters.clone_from(&cmdline.filters)
+            apply_override(
+                overrides,
+                "filters",
+                CliConfig::CMD_USER_FILTERS,
+                &mut instance.filters,
+                cmdline.filters,
+            );
         };
+        instance.filters_glob = cmdline.filters_glob;
+        instance.exclude_glob = cmdline.exclude_glob;
+        if let Some(mode) = cmdline.filters_mode {
+            if instance.filters.is_empty() {
+                errors.push(ConfigError::of_kind(
+                    ConfigErrorKind::MissingArg,
+                    &[CliConfig::CMD_USER_FILTERS_MODE],
+                    format!(
+                        "{} requires name filters, either from `test_filters` property or {}",
+                        CliConfig::CMD_USER_FILTERS_MODE,
+                        CliConfig::CMD_USER_FILTERS
+                    ),
+                ));
+            }
+            instance.filters_mode = mode;
+        }
         if cmdline.mute_keyword {
-            instance.keyword = String::new()
+            apply_override(
+                overrides,
+                "keyword",
+                CliConfig::CMD_USER_MUTE_KEYWORD,
+                &mut instance.keyword,
+                String::new(),
+            );
         };
         if cmdline.ignore_keywords {
-            instance.ignore_keywords = true;
+            apply_override(
+                overrides,
+                "ignore_keywords",
+                CliConfig::CMD_USER_IGNORE_KEYWORDS,
+                &mut instance.ignore_keywords,
+                true,
+            );
         }
         if !cmdline.keyword.is_empty() {
-            instance.keyword.clone_from(&cmdline.keyword)
+            apply_override(
+                overrides,
+                "keyword",
+                CliConfig::CMD_USER_KEYWORD,
+                &mut instance.keyword,
+                cmdline.keyword,
+            );
         };
         if cmdline.only_scene_path {
-            instance.only_scene_path = true;
+            apply_override(
+                overrides,
+                "only_scene_path",
+                CliConfig::CMD_USER_ONLY_SCENE_PATH,
+                &mut instance.only_scene_path,
+                true,
+            );
         }
         if cmdline.quiet_run {
-            instance.quiet_run = true
+            apply_override(
+                overrides,
+                "quiet_run",
+                CliConfig::CMD_USER_QUIET_RUN,
+                &mut instance.quiet_run,
+                true,
+            );
+        }
+        instance.auto_verbosity = cmdline.auto_verbosity;
+        instance.always_summary = cmdline.always_summary;
+        instance.meta = cmdline.meta;
+        instance.min_pass_rate = cmdline.min_pass_rate;
+        instance.description_filters = cmdline.description_filters;
+        instance.group_by = cmdline.group_by.unwrap_or_default();
+        instance.order = cmdline.order.unwrap_or_default();
+        instance.order_file = cmdline.order_file;
+        if let Some(mode) = cmdline.description_filters_mode {
+            instance.description_filters_mode = mode;
+        }
+        instance.summary_file = cmdline.summary_file;
+        instance.priority_threshold = cmdline.priority_threshold;
+        instance.shard = cmdline.shard;
+        instance.bench_baseline = cmdline.bench_baseline;
+        instance.compare_report = cmdline.compare_report;
+        instance.render_report = cmdline.render_report;
+        instance.merge_reports = cmdline.merge_reports;
+        instance.describe = cmdline.describe;
+        instance.bench_save_baseline = cmdline.bench_save_baseline;
+        instance.bench_warn_pct = cmdline.bench_warn_pct;
+        instance.bench_fail_pct = cmdline.bench_fail_pct;
+        instance.perf_regression_pct = cmdline.perf_regression_pct;
+        instance.report_formats = cmdline.report_formats;
+        instance.json_report = cmdline.json_report;
+        instance.junit_report = cmdline.junit_report;
+        instance.csv_report = cmdline.csv_report;
+        instance.status_file = cmdline.status_file;
+        if let Some(dir) = cmdline.artifacts_dir {
+            // Checking the config shouldn't produce any file.
+            if !cmdline.check_config {
+                if let Err(err) = std::fs::create_dir_all(&dir) {
+                    errors.push(ConfigError::of_kind(
+                        ConfigErrorKind::File,
+                        &[CliConfig::CMD_USER_ARTIFACTS_DIR],
+                        format!("couldn't create artifacts directory '{dir}': {err}"),
+                    ));
+                }
+            }
+            // Relative paths of produced artifacts are resolved against the artifacts directory.
+            for path in [
+                &mut instance.summary_file,
+                &mut instance.bench_save_baseline,
+                &mut instance.json_report,
+                &mut instance.junit_report,
+                &mut instance.csv_report,
+                &mut instance.status_file,
+            ]
+            .into_iter()
+            .flatten()
+            {
+                if Path::new(path).is_relative() {
+                    *path = Path::new(&dir).join(&path).to_string_lossy().into_owned();
+                }
+            }
+            instance.artifacts_dir = Some(dir);
+        }
+        instance.no_banner = cmdline.no_banner;
+        instance.timeout = cmdline.timeout;
+        instance.timeout_warn = cmdline.timeout_warn;
+        instance.suite_timeout = cmdline.suite_timeout;
+        instance.bench_timeout = cmdline.bench_timeout;
+        instance.max_total_fail = cmdline.max_total_fail;
+        instance.interactive = cmdline.interactive;
+        instance.exit_marker = cmdline.exit_marker;
+        instance.retries = cmdline.retries.unwrap_or_default();
+        instance.retry_on = cmdline.retry_on;
+        instance.list_json = cmdline.list_json;
+        instance.list_suites = cmdline.list_suites;
+        instance.forbid_focus = cmdline.forbid_focus;
+        instance.fail_empty = cmdline.fail_empty;
+        instance.require_services = cmdline.require_services;
+        instance.update_snapshots = cmdline.update_snapshots;
+        instance.max_message_len = cmdline.max_message_len.unwrap_or_default();
+        if let Some(percent_precision) = cmdline.percent_precision {
+            instance.percent_precision = percent_precision;
+        }
+        instance.ignored_args = cmdline.ignored_args;
+        instance.bench_histogram = cmdline.bench_histogram;
+        instance.bench_group_relative = cmdline.bench_group_relative;
+        instance.bench_filters = cmdline.bench_filters;
+        instance.suites = cmdline.suites;
+        instance.stall_timeout = cmdline
+            .stall_timeout
+            .filter(|secs| *secs > 0.0)
+            .map(Duration::from_secs_f64);
+        instance.banner_fill = cmdline.banner_fill.unwrap_or(' ');
+        instance.no_signal_handler = cmdline.no_signal_handler;
+        instance.only_leaf_failures = cmdline.only_leaf_failures;
+        instance.sort_output = cmdline.sort_output;
+        instance.fail_on_mode_skip = cmdline.fail_on_mode_skip;
+        instance.strict_selection = cmdline.strict_selection;
+        instance.smoke = cmdline.smoke;
+        instance.check_config = cmdline.check_config;
+        instance.time_unit = cmdline.time_unit;
+        instance.no_run = cmdline.no_run;
+        instance.run_ignored = cmdline.run_ignored;
+        if let Some(detail) = cmdline.panic_detail {
+            instance.panic_detail = detail;
         }
+        instance.relative_paths = cmdline.relative_paths.map(|root| {
+            if root.is_empty() {
+                std::env::current_dir().unwrap_or_default()
+            } else {
+                PathBuf::from(root)
+            }
+        });
+        instance.custom_args = Arc::new(cmdline.custom_args);
+        instance.provided = cmdline.provided;
+        instance.shuffle = cmdline.shuffle;
+        instance.run_seed = match cmdline.seed {
+            Some(seed) => (seed, SeedSource::Cli),
+            None => match std::env::var(Self::ENV_SEED) {
+                Ok(value) => (
+                    CliConfig::collect(&mut errors, CliConfig::parse_seed(&value, Self::ENV_SEED)),
+                    SeedSource::Env,
+                ),
+                Err(_) => (random_seed(), SeedSource::Random),
+            },
+        };
+        ConfigError::combine(errors)?;
 
         Ok(instance)
     }
 }
 
+/// Builder of [`RunnerConfig`], with properties of the [`GdTestRunner`](super::GdTestRunner) the config starts from.
+///
+/// [`build`](RunnerConfigBuilder::build) merges them with command line arguments and environment variables, validating
+/// the result.
+#[derive(Clone, Debug, Default)]
+pub struct RunnerConfigBuilder {
+    disallow_focus: bool,
+    disallow_skip: bool,
+    run_rust_tests: bool,
+    run_rust_benchmarks: bool,
+    keyword: String,
+    ignore_keywords: bool,
+    only_scene_path: bool,
+    scene_path: String,
+    filters: Vec<String>,
+    quiet_run: bool,
+    custom_args: Vec<CustomArg>,
+}
+
+impl RunnerConfigBuilder {
+    pub fn disallow_focus(mut self, disallow_focus: bool) -> Self {
+        self.disallow_focus = disallow_focus;
+        self
+    }
+
+    pub fn disallow_skip(mut self, disallow_skip: bool) -> Self {
+        self.disallow_skip = disallow_skip;
+        self
+    }
+
+    pub fn run_rust_tests(mut self, run_rust_tests: bool) -> Self {
+        self.run_rust_tests = run_rust_tests;
+        self
+    }
+
+    pub fn run_rust_benchmarks(mut self, run_rust_benchmarks: bool) -> Self {
+        self.run_rust_benchmarks = run_rust_benchmarks;
+        self
+    }
+
+    pub fn keyword(mut self, keyword: impl Into<String>) -> Self {
+        self.keyword = keyword.into();
+        self
+    }
+
+    pub fn ignore_keywords(mut self, ignore_keywords: bool) -> Self {
+        self.ignore_keywords = ignore_keywords;
+        self
+    }
+
+    pub fn only_scene_path(mut self, only_scene_path: bool) -> Self {
+        self.only_scene_path = only_scene_path;
+        self
+    }
+
+    pub fn scene_path(mut self, scene_path: impl Into<String>) -> Self {
+        self.scene_path = scene_path.into();
+        self
+    }
+
+    pub fn filters(mut self, filters: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.filters = filters.into_iter().map(Into::into).collect();
+        self
+    }
+
+    pub fn quiet_run(mut self, quiet_run: bool) -> Self {
+        self.quiet_run = quiet_run;
+        self
+    }
+
+    pub(crate) fn custom_args(mut self, custom_args: impl IntoIterator<Item = CustomArg>) -> Self {
+        self.custom_args = custom_args.into_iter().collect();
+        self
+    }
+
+    pub fn build(self) -> Result<RunnerConfig, ConfigError> {
+        RunnerConfig::from_builder(self)
+    }
+}
+
+/// Sets `target` to `value`, recording the change if it differs from the value passed by the caller.
+fn apply_override<V: PartialEq + fmt::Debug>(
+    overrides: &mut Vec<String>,
+    property: &str,
+    arg: &str,
+    target: &mut V,
+    value: V,
+) {
+    if *target != value {
+        overrides.push(format!(
+            "{property}: {value:?} (CLI {arg} overrode caller value {target:?})"
+        ));
+    }
+    *target = value;
+}
+
 pub(crate) struct RunnerInfo {
+    pub version: &'static str,
     pub mode: &'static str,
     pub rust_build: &'static str,
     pub godot_build: &'static str,
@@ -351,6 +2675,9 @@ impl RunnerInfo {
         let godot_build = if is_godot_debug() { "debug" } else { "release" };
 
         let mut additional_message = Vec::new();
+        if config.smoke() {
+            additional_message.push("SMOKE RUN - only smoke tests, not a full run".to_owned());
+        }
         if !config.keyword().is_empty() {
             additional_message.push(format!("using KEYWORD: '{}'", config.keyword()));
         }
@@ -366,8 +2693,61 @@ impl RunnerInfo {
         if config.only_scene_path() {
             additional_message.push("scene path specific".to_owned())
         }
+        if let Some(priority_threshold) = config.priority_threshold() {
+            additional_message.push(format!("priority at least: {priority_threshold}"));
+        }
+        if let Some((index, count)) = config.shard() {
+            additional_message.push(format!("shard: {index}/{count}"));
+        }
+        if let Some(min_pass_rate) = config.min_pass_rate() {
+            additional_message.push(format!(
+                "minimum pass rate: {}",
+                format_percent(min_pass_rate)
+            ));
+        }
+        if !config.suites().is_empty() {
+            let mut suites = config.suites().to_vec();
+            if config.sort_output() {
+                suites.sort();
+            }
+            additional_message.push(format!("suites: {}", suites.join(", ")));
+        }
+        if let Some(dir) = config.artifacts_dir() {
+            additional_message.push(format!("artifacts in: {dir}"));
+        }
+        if config.update_snapshots() {
+            additional_message.push("updating resource snapshots".to_owned());
+        }
+        if let Some(budget) = config.max_total_fail() {
+            additional_message.push(format!("total duration budget: {budget}s"));
+        }
+        if config.run_rust_benchmarks() {
+            // Test timeout doesn't apply to benchmarks, which can run much longer.
+            match (config.timeout(), config.bench_timeout()) {
+                (_, Some(timeout)) if timeout > 0.0 => {
+                    additional_message.push(format!("benchmark timeout: {timeout}s"))
+                }
+                (Some(_), _) => {
+                    additional_message.push("test timeout not applied to benchmarks".to_owned())
+                }
+                _ => {}
+            }
+        }
+        if let Some(root) = config.relative_paths() {
+            additional_message.push(format!("paths relative to: {}", root.display()));
+        }
+        match config.shuffle_seed() {
+            Some((seed, source)) => {
+                additional_message.push(format!("shuffled with seed: {seed} ({source})"))
+            }
+            None => {
+                let (seed, source) = config.run_seed();
+                additional_message.push(format!("seed: {seed} ({source})"));
+            }
+        }
 
         Self {
+            version: RUNNER_VERSION,
             mode,
             rust_build,
             godot_build,
@@ -0,0 +1,206 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::fmt::Display;
+
+#[derive(Clone, Debug)]
+enum GlobToken {
+    Literal(char),
+    /// `?` - any single character.
+    AnyChar,
+    /// `*` - any sequence of characters, including empty one.
+    AnySequence,
+    /// `[abc]`, `[a-z]` or negated `[!abc]`.
+    Class {
+        negated: bool,
+        ranges: Vec<(char, char)>,
+    },
+}
+
+impl GlobToken {
+    fn matches(&self, c: char) -> bool {
+        match self {
+            GlobToken::Literal(literal) => *literal == c,
+            GlobToken::AnyChar => true,
+            GlobToken::AnySequence => true,
+            GlobToken::Class { negated, ranges } => {
+                ranges
+                    .iter()
+                    .any(|(start, end)| (*start..=*end).contains(&c))
+                    != *negated
+            }
+        }
+    }
+}
+
+/// Shell-style glob pattern, matched against the whole case name. Special characters are matched literally if escaped with `\`.
+#[derive(Clone, Debug)]
+pub(crate) struct GlobPattern {
+    pattern: String,
+    tokens: Vec<GlobToken>,
+}
+
+impl GlobPattern {
+    pub fn parse(pattern: &str) -> Result<Self, String> {
+        let mut tokens = Vec::new();
+        let mut chars = pattern.chars().peekable();
+
+        while let Some(c) = chars.next() {
+            let token = match c {
+                '?' => GlobToken::AnyChar,
+                '*' => GlobToken::AnySequence,
+                '\\' => match chars.next() {
+                    Some(escaped) => GlobToken::Literal(escaped),
+                    None => return Err(format!("trailing '\\' in glob pattern '{pattern}'")),
+                },
+                '[' => {
+                    let negated = chars.next_if_eq(&'!').is_some();
+                    let mut ranges = Vec::new();
+                    let mut closed = false;
+                    while let Some(start) = chars.next() {
+                        // `]` right after opening is treated as a literal.
+                        if start == ']' && !ranges.is_empty() {
+                            closed = true;
+                            break;
+                        }
+                        let end = if chars.next_if_eq(&'-').is_some() {
+                            // Trailing `-` is treated as a literal.
+                            if chars.next_if_eq(&']').is_some() {
+                                ranges.push((start, start));
+                                ranges.push(('-', '-'));
+                                closed = true;
+                                break;
+                            }
+                            match chars.next() {
+                                Some(end) if end >= start => end,
+                                Some(end) => {
+                                    return Err(format!(
+                                        "invalid range '{start}-{end}' in glob pattern '{pattern}'"
+                                    ))
+                                }
+                                None => break,
+                            }
+                        } else {
+                            start
+                        };
+                        ranges.push((start, end));
+                    }
+                    if !closed {
+                        return Err(format!("unclosed '[' in glob pattern '{pattern}'"));
+                    }
+                    GlobToken::Class { negated, ranges }
+                }
+                literal => GlobToken::Literal(literal),
+            };
+            tokens.push(token);
+        }
+
+        Ok(Self {
+            pattern: pattern.to_owned(),
+            tokens,
+        })
+    }
+
+    pub fn matches(&self, name: &str) -> bool {
+        let name = name.chars().collect::<Vec<_>>();
+        let (mut t, mut n) = (0, 0);
+        // Position of last `*` token and of the name character it was matched against, for backtracking.
+        let mut backtrack: Option<(usize, usize)> = None;
+
+        while n < name.len() {
+            match self.tokens.get(t) {
+                Some(GlobToken::AnySequence) => {
+                    backtrack = Some((t, n));
+                    t += 1;
+                }
+                Some(token) if token.matches(name[n]) => {
+                    t += 1;
+                    n += 1;
+                }
+                _ => match backtrack {
+                    Some((star_t, star_n)) => {
+                        t = star_t + 1;
+                        n = star_n + 1;
+                        backtrack = Some((star_t, star_n + 1));
+                    }
+                    None => return false,
+                },
+            }
+        }
+
+        self.tokens[t..]
+            .iter()
+            .all(|token| matches!(token, GlobToken::AnySequence))
+    }
+}
+
+impl Display for GlobPattern {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.pattern)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::GlobPattern;
+
+    fn matches(pattern: &str, name: &str) -> bool {
+        GlobPattern::parse(pattern).unwrap().matches(name)
+    }
+
+    #[test]
+    fn literal_matches_whole_name() {
+        assert!(matches("simple_test", "simple_test"));
+        assert!(!matches("simple", "simple_test"));
+        assert!(!matches("simple_test", "simple"));
+    }
+
+    #[test]
+    fn star_matches_any_sequence() {
+        assert!(matches("serialize_*_roundtrip", "serialize_dict_roundtrip"));
+        assert!(matches("serialize_*_roundtrip", "serialize__roundtrip"));
+        assert!(matches("*", ""));
+        assert!(matches("*_test", "a_b_test"));
+        assert!(matches("a*b*c", "aXbYbZc"));
+        assert!(!matches("serialize_*_roundtrip", "serialize_dict"));
+    }
+
+    #[test]
+    fn question_mark_matches_single_char() {
+        assert!(matches("test_?", "test_1"));
+        assert!(!matches("test_?", "test_"));
+        assert!(!matches("test_?", "test_12"));
+    }
+
+    #[test]
+    fn classes_match_listed_chars_and_ranges() {
+        assert!(matches("test_[abc]", "test_b"));
+        assert!(!matches("test_[abc]", "test_d"));
+        assert!(matches("test_[a-z0-9]", "test_7"));
+        assert!(!matches("test_[!a-z]", "test_q"));
+        assert!(matches("test_[!a-z]", "test_Q"));
+        assert!(matches("test_[]]", "test_]"));
+        assert!(matches("test_[a-]", "test_-"));
+    }
+
+    #[test]
+    fn escaped_chars_match_literally() {
+        assert!(matches(r"test_\*", "test_*"));
+        assert!(!matches(r"test_\*", "test_a"));
+        assert!(matches(r"test_\?\[", "test_?["));
+        assert!(matches(r"a\\b", r"a\b"));
+    }
+
+    #[test]
+    fn malformed_patterns_are_rejected() {
+        for pattern in ["test_[abc", "test_[z-a]", r"test_\", "[!"] {
+            assert!(
+                GlobPattern::parse(pattern).is_err(),
+                "'{pattern}' should be rejected"
+            );
+        }
+    }
+}
@@ -0,0 +1,75 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::io::{BufRead, IsTerminal, Write};
+
+use super::print::MessageWriter;
+use super::report::{CaseReport, ReportOutcome, RunReport};
+
+/// Interactive browsing is possible only with the terminal on both ends.
+pub(crate) fn is_interactive_terminal(writer: &MessageWriter) -> bool {
+    !writer.to_godot() && std::io::stdin().is_terminal() && std::io::stdout().is_terminal()
+}
+
+/// Lists failed cases by number and prints full details of the ones selected by the user, until empty line is entered.
+pub(crate) fn browse_failures(writer: &MessageWriter, report: &RunReport) {
+    let failed = report
+        .cases
+        .iter()
+        .filter(|case| case.outcome == ReportOutcome::Failed)
+        .collect::<Vec<_>>();
+    if failed.is_empty() {
+        return;
+    }
+
+    let writer = writer.loud();
+    let stdin = std::io::stdin();
+    let mut input = String::new();
+    loop {
+        writer.println("\n   Failures:");
+        for (i, case) in failed.iter().enumerate() {
+            writer.println(&format!("  [{}] {}", i + 1, case_line(case)));
+        }
+        print!("\nEnter failure number to expand it, or press Enter to continue: ");
+        let _ = std::io::stdout().flush();
+
+        input.clear();
+        if stdin.lock().read_line(&mut input).unwrap_or(0) == 0 {
+            break;
+        }
+        let selection = input.trim();
+        if selection.is_empty() {
+            break;
+        }
+        match selection.parse::<usize>() {
+            Ok(number) if (1..=failed.len()).contains(&number) => {
+                writer.println(&case_details(failed[number - 1]));
+            }
+            _ => writer.println(&format!(
+                "'{selection}' is not a number between 1 and {}.",
+                failed.len()
+            )),
+        }
+    }
+}
+
+fn case_line(case: &CaseReport) -> String {
+    match &case.failure_kind {
+        Some(kind) => format!("{} > {} [{kind}]", case.file, case.display_name()),
+        None => format!("{} > {}", case.file, case.display_name()),
+    }
+}
+
+fn case_details(case: &CaseReport) -> String {
+    let mut details = format!("\n-- {}", case_line(case));
+    if let Some(message) = &case.message {
+        details.push_str(&format!("\n{message}"));
+    }
+    for sub_failure in &case.sub_failures {
+        details.push_str(&format!("\n  - {sub_failure}"));
+    }
+    details
+}
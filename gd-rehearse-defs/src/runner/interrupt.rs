@@ -0,0 +1,68 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::sync::atomic::{AtomicBool, Ordering};
+
+static INTERRUPTED: AtomicBool = AtomicBool::new(false);
+
+/// Handler replaced by [`install_handler`], restored once the run ends.
+#[cfg(unix)]
+static PREVIOUS_HANDLER: std::sync::Mutex<Option<libc::sighandler_t>> = std::sync::Mutex::new(None);
+
+/// Exit code of interrupted runs, as used by shells for processes ended by `SIGINT`.
+pub(crate) const INTERRUPTED_EXIT_CODE: i32 = 130;
+
+/// Installs `SIGINT` handler which only marks the run as interrupted, so the runner can stop between cases. The default
+/// handler is set back after the first signal, so the second one ends the process right away.
+///
+/// Does nothing on platforms other than Unix.
+pub(crate) fn install_handler() {
+    #[cfg(unix)]
+    {
+        extern "C" fn handle_interrupt(_signal: libc::c_int) {
+            INTERRUPTED.store(true, Ordering::SeqCst);
+            // SAFETY: `signal` is async-signal-safe.
+            unsafe {
+                libc::signal(libc::SIGINT, libc::SIG_DFL);
+            }
+        }
+
+        // SAFETY: the handler only stores into an atomic and calls `signal`, which are async-signal-safe.
+        let previous = unsafe {
+            libc::signal(
+                libc::SIGINT,
+                handle_interrupt as extern "C" fn(libc::c_int) as libc::sighandler_t,
+            )
+        };
+        if previous != libc::SIG_ERR {
+            if let Ok(mut handler) = PREVIOUS_HANDLER.lock() {
+                handler.get_or_insert(previous);
+            }
+        }
+    }
+}
+
+/// Restores the `SIGINT` handler replaced by [`install_handler`]. Does nothing if it wasn't installed.
+pub(crate) fn restore_handler() {
+    #[cfg(unix)]
+    {
+        let Some(previous) = PREVIOUS_HANDLER
+            .lock()
+            .ok()
+            .and_then(|mut handler| handler.take())
+        else {
+            return;
+        };
+        // SAFETY: `previous` was the handler in place before `install_handler`.
+        unsafe {
+            libc::signal(libc::SIGINT, previous);
+        }
+    }
+}
+
+pub(crate) fn is_interrupted() -> bool {
+    INTERRUPTED.load(Ordering::SeqCst)
+}
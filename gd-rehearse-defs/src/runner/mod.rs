@@ -4,12 +4,23 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::RwLock;
+
 use godot::builtin::GString;
 
+pub(crate) mod baseline;
 pub(crate) mod class;
 pub mod config;
+pub(crate) mod glob;
+pub(crate) mod interactive;
+pub(crate) mod interrupt;
 pub(crate) mod panic;
 pub(crate) mod print;
+pub mod report;
+pub mod shuffle;
+pub(crate) mod watchdog;
 
 pub use class::GdTestRunner;
 
@@ -25,8 +36,48 @@ pub(crate) fn is_rust_debug() -> bool {
     cfg!(debug_assertions)
 }
 
+static RELATIVE_PATHS_ROOT: RwLock<Option<PathBuf>> = RwLock::new(None);
+
+/// Sets the root against which source paths are made relative, set with `--relative-paths`.
+pub(crate) fn set_relative_paths_root(root: Option<&Path>) {
+    *RELATIVE_PATHS_ROOT
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner()) = root.map(Path::to_path_buf);
+}
+
+/// Makes the source `file` relative to the `--relative-paths` root. Paths outside of the root are kept as they are.
+pub(crate) fn relative_path(file: &str) -> String {
+    let root = RELATIVE_PATHS_ROOT
+        .read()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    match root
+        .as_deref()
+        .and_then(|root| Path::new(file).strip_prefix(root).ok())
+    {
+        Some(relative) => relative.to_string_lossy().into_owned(),
+        None => file.to_owned(),
+    }
+}
+
+/// Number of decimal places of printed percentages, set with `--percent-precision`.
+static PERCENT_PRECISION: AtomicUsize = AtomicUsize::new(2);
+
+pub(crate) fn set_percent_precision(precision: usize) {
+    PERCENT_PRECISION.store(precision, Ordering::SeqCst);
+}
+
+/// Formats `value` as percentage, rounded to the `--percent-precision` decimal places.
+pub(crate) fn format_percent(value: f64) -> String {
+    format!("{value:.*}%", PERCENT_PRECISION.load(Ordering::SeqCst))
+}
+
+/// Like [`format_percent`], but always with a sign - for differences against previous results.
+pub(crate) fn format_percent_delta(value: f64) -> String {
+    format!("{value:+.*}%", PERCENT_PRECISION.load(Ordering::SeqCst))
+}
+
 pub(crate) fn extract_file_subtitle(file: &str) -> &str {
-    if let Some(sep_pos) = file.rfind(&['/', '\\']) {
+    if let Some(sep_pos) = file.rfind(['/', '\\']) {
         &file[sep_pos + 1..]
     } else {
         file
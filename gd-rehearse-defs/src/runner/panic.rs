@@ -5,9 +5,76 @@
  */
 
 use std::any::Any;
+use std::backtrace::{Backtrace, BacktraceStatus};
+use std::cell::RefCell;
 use std::error::Error;
 use std::fmt::Display;
 use std::panic::UnwindSafe;
+use std::sync::atomic::{AtomicU8, Ordering};
+
+use crate::assertions::soft::SoftAssertionFailures;
+use crate::assertions::take_assertion_marker;
+use crate::cases::rust_test_case::SkipRequest;
+
+/// How much of a caught panic is recorded in the failure, selected with `--panic-detail`.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub(crate) enum PanicDetail {
+    /// Only the fact that the case panicked.
+    Minimal,
+    #[default]
+    Message,
+    /// Message followed by the backtrace, if captured due to `RUST_BACKTRACE`.
+    Backtrace,
+}
+
+impl PanicDetail {
+    pub fn from_arg(value: &str) -> Option<Self> {
+        match value {
+            "minimal" => Some(Self::Minimal),
+            "message" => Some(Self::Message),
+            "backtrace" => Some(Self::Backtrace),
+            _ => None,
+        }
+    }
+
+    fn from_u8(value: u8) -> Self {
+        match value {
+            0 => Self::Minimal,
+            2 => Self::Backtrace,
+            _ => Self::Message,
+        }
+    }
+}
+
+impl Display for PanicDetail {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PanicDetail::Minimal => f.write_str("minimal"),
+            PanicDetail::Message => f.write_str("message"),
+            PanicDetail::Backtrace => f.write_str("backtrace"),
+        }
+    }
+}
+
+static PANIC_DETAIL: AtomicU8 = AtomicU8::new(PanicDetail::Message as u8);
+
+thread_local! {
+    /// Backtrace of the last panic on this thread, captured by the hook installed for [`PanicDetail::Backtrace`].
+    static LAST_BACKTRACE: RefCell<Option<Backtrace>> = const { RefCell::new(None) };
+}
+
+/// Sets the detail of all panics caught afterwards. For [`PanicDetail::Backtrace`], installs panic hook capturing the backtrace
+/// before calling the previous hook.
+pub(crate) fn set_panic_detail(detail: PanicDetail) {
+    PANIC_DETAIL.store(detail as u8, Ordering::SeqCst);
+    if detail == PanicDetail::Backtrace {
+        let previous = std::panic::take_hook();
+        std::panic::set_hook(Box::new(move |info| {
+            LAST_BACKTRACE.with(|last| *last.borrow_mut() = Some(Backtrace::capture()));
+            previous(info);
+        }));
+    }
+}
 
 pub(crate) type UnwindResult<T> = Result<Result<T, Box<dyn Any + Send>>, Box<dyn Any + Send>>;
 
@@ -15,31 +82,103 @@ pub(crate) fn unwind_result<T>(res: UnwindResult<T>) -> Result<T, UnwindError> {
     match res {
         Ok(inner_res) => match inner_res {
             Ok(ok) => Ok(ok),
-            Err(err) => Err(unpack_err(err)),
+            Err(err) => Err(with_panic_detail(unpack_err(&*err))),
         },
-        Err(err) => Err(unpack_err(err)),
+        Err(err) => Err(with_panic_detail(unpack_err(&*err))),
     }
 }
 
-fn unpack_err(err: Box<dyn Any + Send>) -> UnwindError {
-    match err.downcast_ref::<&str>() {
-        Some(str) => UnwindError {
-            message: str.to_string(),
-        },
+/// Applies the [`PanicDetail`] to error from plain panic. Skips and soft assertion failures are kept as they are.
+fn with_panic_detail(mut error: UnwindError) -> UnwindError {
+    let backtrace = LAST_BACKTRACE.with(|last| last.borrow_mut().take());
+    if error.skipped || !error.sub_failures.is_empty() {
+        return error;
+    }
+    match PanicDetail::from_u8(PANIC_DETAIL.load(Ordering::SeqCst)) {
+        PanicDetail::Minimal => error.message = "panicked".to_owned(),
+        PanicDetail::Message => {}
+        PanicDetail::Backtrace => {
+            if let Some(backtrace) = backtrace.filter(|bt| bt.status() == BacktraceStatus::Captured)
+            {
+                error.message = format!("{}\n{backtrace}", error.message);
+            }
+        }
+    }
+    error
+}
+
+pub(crate) fn unpack_err(err: &(dyn Any + Send)) -> UnwindError {
+    let assertion = take_assertion_marker();
+    if let Some(soft) = err.downcast_ref::<SoftAssertionFailures>() {
+        return UnwindError {
+            message: soft.message(),
+            sub_failures: soft.failures.clone(),
+            skipped: false,
+            assertion: true,
+        };
+    }
+    if let Some(skip) = err.downcast_ref::<SkipRequest>() {
+        return UnwindError {
+            message: skip.reason.clone(),
+            sub_failures: Vec::new(),
+            skipped: true,
+            assertion: false,
+        };
+    }
+    let message = match err.downcast_ref::<&str>() {
+        Some(str) => str.to_string(),
         None => match err.downcast_ref::<String>() {
-            Some(string) => UnwindError {
-                message: string.to_owned(),
-            },
-            None => UnwindError {
-                message: "cannot retrieve panic message".to_owned(),
-            },
+            Some(string) => string.to_owned(),
+            None => "cannot retrieve panic message".to_owned(),
         },
+    };
+    // Besides the marked ones, failures of `assert!` without custom message, `assert_eq!` and `assert_ne!` are recognized by
+    // the message. `assert!` with custom message can't be told apart from `panic!`.
+    let assertion = assertion || message.starts_with("assertion");
+    UnwindError {
+        message,
+        sub_failures: Vec::new(),
+        skipped: false,
+        assertion,
     }
 }
 
 #[derive(Debug)]
 pub(crate) struct UnwindError {
     message: String,
+    /// Separate failures collected by soft assertions.
+    sub_failures: Vec<String>,
+    /// Case requested to be skipped with `message` as the reason.
+    skipped: bool,
+    /// Raised by a failed assertion, rather than any other panic.
+    assertion: bool,
+}
+
+impl UnwindError {
+    pub fn new(message: String) -> Self {
+        Self {
+            message,
+            sub_failures: Vec::new(),
+            skipped: false,
+            assertion: false,
+        }
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    pub fn sub_failures(&self) -> &[String] {
+        &self.sub_failures
+    }
+
+    pub fn is_skipped(&self) -> bool {
+        self.skipped
+    }
+
+    pub fn is_assertion(&self) -> bool {
+        self.assertion
+    }
 }
 
 impl Display for UnwindError {
@@ -60,3 +199,45 @@ where
     });
     unwind_result(result)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::unpack_err;
+
+    fn unpacked(code: impl FnOnce() + std::panic::UnwindSafe) -> super::UnwindError {
+        let hook = std::panic::take_hook();
+        std::panic::set_hook(Box::new(|_| {}));
+        let payload = std::panic::catch_unwind(code).expect_err("code should panic");
+        std::panic::set_hook(hook);
+        unpack_err(&*payload)
+    }
+
+    #[test]
+    fn crate_assertion_macros_are_assertions() {
+        let error = unpacked(|| crate::assert_panics!(|| {}));
+        assert!(error.is_assertion(), "{}", error.message());
+    }
+
+    #[test]
+    fn std_assertions_without_custom_message_are_assertions() {
+        let values: Vec<i32> = Vec::new();
+        assert!(unpacked(|| assert_eq!(values.len(), 1)).is_assertion());
+        assert!(unpacked(|| assert!(!values.is_empty())).is_assertion());
+    }
+
+    #[test]
+    fn other_panics_are_not_assertions() {
+        assert!(!unpacked(|| panic!("failed for other reason")).is_assertion());
+        let values: Vec<i32> = Vec::new();
+        assert!(!unpacked(|| {
+            values.first().expect("no value");
+        })
+        .is_assertion());
+    }
+
+    #[test]
+    fn caught_assertion_doesnt_mark_later_panic() {
+        let _ = unpacked(|| crate::assert_panics!(|| {}));
+        assert!(!unpacked(|| panic!("unrelated")).is_assertion());
+    }
+}
@@ -4,25 +4,220 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
+use crate::cases::rust_test_case::RustTestCase;
 use crate::cases::{Case, CaseOutcome};
 use crate::registry::bench::BenchResult;
 use crate::registry::itest::TestResult;
 use crate::runner::extract_file_subtitle;
 
-use super::config::{RunnerConfig, RunnerInfo};
+use super::config::{GroupBy, RunnerConfig, RunnerInfo, TimeUnit};
 use super::is_headless_run;
+use super::report::{CaseReport, ReportCaseKind, ReportDelta, ReportOutcome, RunReport};
 
+use godot::builtin::meta::ToGodot;
+use godot::engine::utilities::printerr;
 use godot::log::godot_print;
 
+use std::cell::RefCell;
+use std::fs::File;
+use std::io::Write;
+use std::rc::Rc;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// Width of the output when it can't be detected, and of every file sink.
+pub(crate) const DEFAULT_WIDTH: usize = 80;
+// Narrower output would break the banners.
+const MIN_WIDTH: usize = 40;
+
+/// Line of console output held back by `--auto-verbosity`. File sink isn't held back, as it doesn't depend on verbosity.
+struct BufferedLine {
+    /// Printed also with `--quiet-run`.
+    loud: bool,
+    console: String,
+}
+
+#[derive(Clone)]
 pub(crate) struct MessageWriter {
     to_godot: bool,
     quiet: bool,
+    width: usize,
+    /// Failure messages longer than this are truncated. `0` means no truncation.
+    max_message_len: usize,
+    /// Character padding the centered run information.
+    banner_fill: char,
+    /// Current line of `--format=dots` output, if enabled.
+    dots_line: Option<Rc<RefCell<String>>>,
+    /// Only failed tests are printed, each in a single self-contained entry.
+    only_failures: bool,
+    /// Unit of the printed durations, overriding the default one of every place they're printed in.
+    time_unit: Option<TimeUnit>,
+    /// Output held back until the outcome of the run is known, with `--auto-verbosity`. `None` inside once it's flushed.
+    buffer: Option<Rc<RefCell<Option<Vec<BufferedLine>>>>>,
+    /// Redirects console output, instead of printing it to stdout or Godot console.
+    console: Option<Rc<RefCell<dyn Write>>>,
+    /// Console output is printed to stderr, keeping stdout only for the reports printed there.
+    to_stderr: bool,
+    /// Mirrors all output regardless of `quiet`, always rendered with [`DEFAULT_WIDTH`].
+    summary_file: Option<SummaryFile>,
+}
+
+/// File sink, shared with [`DetachedWriter`].
+type SummaryFile = Arc<Mutex<dyn Write + Send>>;
+
+/// Part of [`MessageWriter`] which can be moved to another thread. Prints to the standard error instead of the console and
+/// to the file sink, without wrapping or holding back the output.
+pub(crate) struct DetachedWriter {
+    quiet: bool,
+    summary_file: Option<SummaryFile>,
+}
+
+impl DetachedWriter {
+    pub fn println(&self, message: &str) {
+        if let Some(file) = &self.summary_file {
+            if let Ok(mut file) = file.lock() {
+                let _ = writeln!(file, "{}", message);
+            }
+        }
+        if !self.quiet {
+            eprintln!("{}", message);
+        }
+    }
 }
 
 impl MessageWriter {
     pub fn new(quiet: bool) -> Self {
         let to_godot = !is_headless_run();
-        Self { to_godot, quiet }
+        Self::with_console(quiet, to_godot, Self::detect_console_width(to_godot))
+    }
+
+    fn with_console(quiet: bool, to_godot: bool, width: usize) -> Self {
+        Self {
+            to_godot,
+            quiet,
+            width,
+            max_message_len: 0,
+            banner_fill: ' ',
+            dots_line: None,
+            only_failures: false,
+            time_unit: None,
+            buffer: None,
+            console: None,
+            to_stderr: false,
+            summary_file: None,
+        }
+    }
+
+    pub fn with_max_message_len(mut self, max_message_len: usize) -> Self {
+        self.max_message_len = max_message_len;
+        self
+    }
+
+    pub fn with_stderr_console(mut self, to_stderr: bool) -> Self {
+        self.to_stderr = to_stderr;
+        self
+    }
+
+    pub fn with_time_unit(mut self, time_unit: Option<TimeUnit>) -> Self {
+        self.time_unit = time_unit;
+        self
+    }
+
+    /// Formats the duration in the unit from `--time-unit`, or in the `default` one if it isn't provided.
+    pub fn format_duration(&self, duration: Duration, default: TimeUnit) -> String {
+        self.time_unit.unwrap_or(default).format(duration)
+    }
+
+    pub fn with_banner_fill(mut self, banner_fill: char) -> Self {
+        self.banner_fill = banner_fill;
+        self
+    }
+
+    pub fn with_dots(mut self, dots: bool) -> Self {
+        self.dots_line = dots.then(Rc::default);
+        self
+    }
+
+    pub fn with_only_failures(mut self, only_failures: bool) -> Self {
+        self.only_failures = only_failures;
+        self
+    }
+
+    /// Holds back all output until [`MessageWriter::flush_buffer`]. Needs to be created as `quiet`, so the output which isn't
+    /// printed with `--quiet-run` can be told apart.
+    pub fn with_auto_verbosity(mut self, auto_verbosity: bool) -> Self {
+        self.buffer = auto_verbosity.then(|| Rc::new(RefCell::new(Some(Vec::new()))));
+        self
+    }
+
+    /// Ends buffering of `--auto-verbosity`, printing all held back output if the run `failed`, or only the output of
+    /// `--quiet-run` otherwise. Does nothing if it was already flushed.
+    pub fn flush_buffer(&self, failed: bool) {
+        let Some(lines) = self
+            .buffer
+            .as_ref()
+            .and_then(|buffer| buffer.borrow_mut().take())
+        else {
+            return;
+        };
+        for line in lines.into_iter().filter(|line| failed || line.loud) {
+            self.println_console(&line.console);
+        }
+    }
+
+    fn is_buffering(&self) -> bool {
+        self.buffer
+            .as_ref()
+            .is_some_and(|buffer| buffer.borrow().is_some())
+    }
+
+    /// Output is skipped altogether, as it's quiet, not held back for `--auto-verbosity` and there is no file sink.
+    fn is_silenced(&self) -> bool {
+        self.quiet && !self.is_buffering() && self.summary_file.is_none()
+    }
+
+    /// Whether the console output can be appended to the current line, which isn't possible in Godot console, when the output
+    /// is held back or when it's not printed to console at all.
+    fn appends_lines(&self) -> bool {
+        !self.to_godot && !self.is_buffering() && !self.quiet && self.console.is_none()
+    }
+
+    /// Creates writer which additionally mirrors its output to the `summary_file`, always rendered with 80 columns.
+    pub fn with_summary_file(quiet: bool, summary_file: &str) -> std::io::Result<Self> {
+        let file = File::create(summary_file)?;
+        let mut writer = Self::new(quiet);
+        writer.summary_file = Some(Arc::new(Mutex::new(file)));
+        Ok(writer)
+    }
+
+    #[cfg(test)]
+    fn with_sinks(
+        quiet: bool,
+        width: usize,
+        console: Rc<RefCell<dyn Write>>,
+        summary_file: SummaryFile,
+    ) -> Self {
+        let mut writer = Self::with_console(quiet, false, width);
+        writer.console = Some(console);
+        writer.summary_file = Some(summary_file);
+        writer
+    }
+
+    /// Returns the same writer, but ignoring `quiet` setting for console output.
+    pub fn loud(&self) -> Self {
+        let mut writer = self.clone();
+        writer.quiet = false;
+        writer
+    }
+
+    fn detect_console_width(to_godot: bool) -> usize {
+        if to_godot {
+            return DEFAULT_WIDTH;
+        }
+        std::env::var("COLUMNS")
+            .ok()
+            .and_then(|columns| columns.trim().parse::<usize>().ok())
+            .map_or(DEFAULT_WIDTH, |columns| columns.max(MIN_WIDTH))
     }
 
     pub fn to_godot(&self) -> bool {
@@ -30,100 +225,406 @@ impl MessageWriter {
     }
 
     pub fn println(&self, message: &str) {
+        self.println_rendered(|_| message.to_owned());
+    }
+
+    /// Prints message rendered separately for every sink, according to its width. `quiet` applies only to the console.
+    fn println_rendered(&self, render: impl Fn(usize) -> String) {
+        self.println_file(&render(DEFAULT_WIDTH));
+        if let Some(buffer) = &self.buffer {
+            if let Some(lines) = buffer.borrow_mut().as_mut() {
+                lines.push(BufferedLine {
+                    loud: !self.quiet,
+                    console: render(self.width),
+                });
+                return;
+            }
+        }
         if self.quiet {
             return;
         }
-        if self.to_godot() {
+        self.println_console(&render(self.width));
+    }
+
+    fn println_console(&self, message: &str) {
+        if let Some(console) = &self.console {
+            let _ = writeln!(console.borrow_mut(), "{}", message);
+        } else if self.to_godot() && self.to_stderr {
+            printerr(&[message.to_variant()]);
+        } else if self.to_godot() {
             godot_print!("{}", message);
+        } else if self.to_stderr {
+            eprintln!("{}", message)
         } else {
             println!("{}", message)
         }
     }
 
+    /// Appends `part` to the current console line, only if [`MessageWriter::appends_lines`].
+    fn print_console_part(&self, part: &str) {
+        if self.to_stderr {
+            eprint!("{part}");
+        } else {
+            print!("{part}");
+            let _ = std::io::stdout().flush();
+        }
+    }
+
+    /// Writes the message only to file sink, if present.
+    fn println_file(&self, message: &str) {
+        if let Some(file) = &self.summary_file {
+            // Failing to mirror the output shouldn't break the run itself.
+            if let Ok(mut file) = file.lock() {
+                let _ = writeln!(file, "{}", message);
+            }
+        }
+    }
+
+    /// Creates writer usable from other threads. Output held back by `--auto-verbosity` is printed right away, as it can't
+    /// be buffered there.
+    pub fn detached(&self) -> DetachedWriter {
+        DetachedWriter {
+            quiet: self.quiet && !self.is_buffering(),
+            summary_file: self.summary_file.clone(),
+        }
+    }
+
     pub fn print_begin(&self) {
-        self.println(&format!(
-            "{hf}\n{hp}{h:^40}{hp}\n{hf}",
-            hf = "-".repeat(80),
-            hp = "-".repeat(20),
-            h = "Running gd-rehearse"
-        ));
+        self.println_rendered(|width| {
+            let side = width / 4;
+            format!(
+                "{hf}\n{hp}{h:^center$}{hp}\n{hf}",
+                hf = "-".repeat(width),
+                hp = "-".repeat(side),
+                h = "Running gd-rehearse",
+                center = width - 2 * side
+            )
+        });
     }
 
     pub fn print_success(&self) {
-        self.println(&format!(
-            "\n{hf}\n{hp}{h:^38}{hp}\n{hf}",
-            hf = "= ".repeat(40),
-            hp = " =".repeat(10),
-            h = "! SUCCESS !"
-        ));
+        self.print_outcome_banner("= ", " =", "! SUCCESS !");
     }
 
     pub fn print_failure(&self) {
-        self.println(&format!(
-            "\n{hf}\n{hp}{h:^38}{hp}\n{hf}",
-            hf = "! ".repeat(40),
-            hp = " !".repeat(10),
-            h = "FAILURE"
-        ));
+        self.print_outcome_banner("! ", " !", "FAILURE");
+    }
+
+    fn print_outcome_banner(&self, full: &str, side: &str, title: &str) {
+        self.println_rendered(|width| {
+            let side_repeats = width / 8;
+            format!(
+                "\n{hf}\n{hp}{h:^center$}{hp}\n{hf}",
+                hf = full.repeat(width / 2),
+                hp = side.repeat(side_repeats),
+                h = title,
+                center = width - 4 * side_repeats - 2
+            )
+        });
     }
 
     pub fn print_horizontal_separator(&self) {
-        self.println(&"-".repeat(80).to_string());
+        self.println_rendered(|width| "-".repeat(width));
+    }
+
+    /// Prints message centered in every sink, wrapping it by words if it's too long to fit.
+    fn println_centered(&self, message: &str) {
+        let fill = self.banner_fill;
+        self.println_rendered(|width| {
+            // Other fill characters are kept apart from the text.
+            let margin = if fill == ' ' { 0 } else { 2 };
+            wrap_words(message, width.saturating_sub(margin))
+                .iter()
+                .map(|line| {
+                    if margin == 0 {
+                        return format!("{line:^width$}");
+                    }
+                    let line = format!(" {line} ");
+                    let padding = width.saturating_sub(line.chars().count());
+                    let left = fill.to_string().repeat(padding / 2);
+                    let right = fill.to_string().repeat(padding - padding / 2);
+                    format!("{left}{line}{right}")
+                })
+                .collect::<Vec<_>>()
+                .join("\n")
+        });
     }
 
     pub fn print_summary_info(&self, config: &RunnerConfig) {
         let RunnerInfo {
+            version,
             mode,
             rust_build,
             godot_build,
             additional_message,
         } = RunnerInfo::gather(config);
 
-        self.println(&format!(
-            "{:^80}",
-            format!(
-                "Began run in {mode} mode in scene: {scene_path}",
-                scene_path = &config.scene_path()
-            )
+        self.println_centered(&format!(
+            "Began run in {mode} mode in scene: {scene_path}",
+            scene_path = &config.scene_path()
         ));
 
-        self.println(&format!(
-            "{:^80}",
-            format!("Rust build: {rust_build}; Godot build: {godot_build}")
+        self.println_centered(&format!(
+            "gd-rehearse {version}; Rust build: {rust_build}; Godot build: {godot_build}"
         ));
 
         if !additional_message.is_empty() {
-            self.println(&format!("{:^80}", additional_message.join(" & ")));
+            self.println_centered(&additional_message.join(" & "));
+        }
+
+        // Listed items are sorted with `--sort-output`, so the output doesn't depend on the order they were provided in.
+        let list = |items: &[String]| {
+            let mut items = items.to_vec();
+            if config.sort_output() {
+                items.sort();
+            }
+            items.join("\n   * ")
+        };
+
+        if !config.overrides().is_empty() {
+            self.println(&format!(
+                "   Command line overrides:\n   * {}\n",
+                list(config.overrides())
+            ));
         }
 
-        if !config.filters().is_empty() {
+        if config.filters().len() > 1 {
+            self.println(&format!(
+                "   Using filters (combined with {}):\n   * {}\n",
+                config.filters_mode(),
+                list(config.filters())
+            ));
+        } else if !config.filters().is_empty() {
             self.println(&format!(
                 "   Using filters:\n   * {}\n",
-                config.filters().join("\n   * ")
+                list(config.filters())
+            ));
+        }
+
+        if config.run_rust_benchmarks() && !config.bench_filters().is_empty() {
+            self.println(&format!(
+                "   Using benchmark filters, replacing the above for benchmarks:\n   * {}\n",
+                list(config.bench_filters())
+            ));
+        }
+
+        if !config.filters_glob().is_empty() {
+            let globs = config
+                .filters_glob()
+                .iter()
+                .map(|glob| glob.to_string())
+                .collect::<Vec<_>>();
+            self.println(&format!("   Using glob filters:\n   * {}\n", list(&globs)));
+        }
+
+        if !config.description_filters().is_empty() {
+            self.println(&format!(
+                "   Using description filters ({} with name filters):\n   * {}\n",
+                config.description_filters_mode(),
+                list(config.description_filters())
             ));
         }
 
+        if !config.meta().is_empty() {
+            let meta = config
+                .meta()
+                .iter()
+                .map(|(key, value)| format!("{key}={value}"))
+                .collect::<Vec<_>>();
+            self.println(&format!("   Run metadata:\n   * {}\n", list(&meta)));
+        }
+
         self.println("");
     }
 
     pub fn print_test_pre(&self, test: impl Case, last_file: &mut Option<String>) {
-        if self.quiet {
+        if self.is_silenced() || self.dots_line.is_some() || self.only_failures {
             return;
         }
         self.print_file_header(test.get_case_file(), last_file);
         // If printing to Godot console, the result will be printed as a whole string. That's because every `godot_print!` prints
         // the whole line only and there is no alternative for appending to the godot console output.
-        if self.to_godot {
+        if !self.appends_lines() {
+            return;
+        }
+        self.print_console_part(&format!("   -- {} ... ", test.get_case_display_name()));
+    }
+
+    fn print_dot(&self, line: &mut String, dot: char) {
+        if line.is_empty() && self.appends_lines() {
+            self.print_console_part("   ");
+        }
+        line.push(dot);
+        if self.appends_lines() {
+            self.print_console_part(&dot.to_string());
+        }
+        // Whole line is printed to Godot console and file sink at once, as they can't append to it.
+        if line.chars().count() + 3 >= self.width {
+            self.end_dots_line(line);
+        }
+    }
+
+    fn end_dots_line(&self, line: &mut String) {
+        if line.is_empty() {
+            return;
+        }
+        if self.appends_lines() {
+            self.println_console("");
+            self.println_file(&format!("   {line}"));
+        } else {
+            self.println(&format!("   {line}"));
+        }
+        line.clear();
+    }
+
+    /// Ends `--format=dots` output with a legend and details of the failed tests, which weren't printed while they finished.
+    pub fn print_dots_summary(&self, report: &RunReport) {
+        let Some(line) = &self.dots_line else {
+            return;
+        };
+        if self.is_silenced() {
             return;
         }
-        print!("   -- {} ... ", test.get_case_name());
+        self.end_dots_line(&mut line.borrow_mut());
+        self.println("\n   . passed, F failed, S skipped");
+
+        let failed = report.cases.iter().filter(|case| {
+            case.kind == ReportCaseKind::Test && case.outcome == ReportOutcome::Failed
+        });
+        for case in failed {
+            self.println(&format!(
+                "\n   -- {}::{} ... failed:\n{}",
+                case.file,
+                case.display_name(),
+                self.truncate(case.message.as_deref().unwrap_or_default())
+            ));
+        }
+    }
+
+    /// Prints the saved report loaded with `--render-report` the same way as the cases are printed during the run.
+    pub fn print_rendered_report(&self, path: &str, report: &RunReport) {
+        self.println(&format!("   Rendering report: {path}"));
+        for (key, value) in &report.meta {
+            self.println(&format!("   {key}: {value}"));
+        }
+
+        for (kind, title) in [
+            (ReportCaseKind::Test, "Rust tests"),
+            (ReportCaseKind::Bench, "Rust benchmarks"),
+        ] {
+            let cases = report.cases.iter().filter(|case| case.kind == kind);
+            let mut last_file = None;
+            for case in cases {
+                if last_file.is_none() {
+                    self.println("");
+                    self.print_horizontal_separator();
+                    self.println(&format!("   {title}"));
+                    self.print_horizontal_separator();
+                    self.println("");
+                }
+                self.print_file_header(&case.file, &mut last_file);
+                let outcome = match case.outcome {
+                    ReportOutcome::Passed => CaseOutcome::Passed,
+                    ReportOutcome::Failed => CaseOutcome::Failed,
+                    ReportOutcome::Skipped => CaseOutcome::Skipped,
+                };
+                match &case.message {
+                    Some(message) if case.outcome == ReportOutcome::Failed => {
+                        self.println(&format!(
+                            "   -- {} ... {outcome}:\n{}",
+                            case.display_name(),
+                            self.truncate(message)
+                        ))
+                    }
+                    _ => self.println(&format!("   -- {} ... {outcome}", case.display_name())),
+                }
+            }
+        }
+
+        self.print_report_result(report);
+    }
+
+    /// Prints the counts of the whole run, as the last lines of `--render-report` output or with `--always-summary`.
+    pub fn print_report_result(&self, report: &RunReport) {
+        let counts = report.counts();
+        let run_time = self.format_duration(report.duration(), TimeUnit::Seconds);
+        self.println(&format!(
+            "\nResult: {} passed; {} failed, {} skipped. Elapsed: {run_time}.",
+            counts.passed, counts.failed, counts.skipped
+        ));
+        if let Some(reason) = &report.aborted {
+            self.println(&format!("Run aborted: {reason}"));
+        }
+    }
+
+    /// Prints details of the finished `cases`, clustered according to `--group-by`.
+    pub fn print_grouped_cases(&self, cases: &[&CaseReport], group_by: GroupBy) {
+        match group_by {
+            GroupBy::Order => {}
+            GroupBy::Outcome => {
+                for (outcome, label) in [
+                    (ReportOutcome::Failed, "Failed"),
+                    (ReportOutcome::Skipped, "Skipped"),
+                    (ReportOutcome::Passed, "Passed"),
+                ] {
+                    let group = cases
+                        .iter()
+                        .filter(|case| case.outcome == outcome)
+                        .collect::<Vec<_>>();
+                    if group.is_empty() {
+                        continue;
+                    }
+                    self.println(&format!("\n  {label} ({}):", group.len()));
+                    for case in group {
+                        self.println(&format!(
+                            "  * {}::{}{}",
+                            case.file,
+                            case.display_name(),
+                            self.case_message(case)
+                        ));
+                    }
+                }
+            }
+            GroupBy::Suite => {
+                let mut suites: Vec<(&str, Vec<&CaseReport>)> = Vec::new();
+                for case in cases {
+                    match suites.iter_mut().find(|(file, _)| *file == case.file) {
+                        Some((_, group)) => group.push(case),
+                        None => suites.push((&case.file, vec![case])),
+                    }
+                }
+                for (file, group) in suites {
+                    self.println(&format!("\n  {file} ({}):", group.len()));
+                    for case in group {
+                        let outcome = match case.outcome {
+                            ReportOutcome::Passed => "passed",
+                            ReportOutcome::Failed => "failed",
+                            ReportOutcome::Skipped => "skipped",
+                        };
+                        self.println(&format!(
+                            "  * {} ... {outcome}{}",
+                            case.display_name(),
+                            self.case_message(case)
+                        ));
+                    }
+                }
+            }
+        }
+    }
+
+    /// First line of the failure or skip message, prefixed for appending after the case name.
+    fn case_message(&self, case: &CaseReport) -> String {
+        case.message
+            .as_deref()
+            .and_then(|message| message.lines().next())
+            .filter(|line| !line.is_empty())
+            .map(|line| format!(": {}", self.truncate(line)))
+            .unwrap_or_default()
     }
 
     fn print_file_header(&self, file: &str, last_file: &mut Option<String>) {
         // Check if we need to open a new category for a file.
-        let is_new_file = last_file
-            .as_ref()
-            .map_or(true, |last_file| last_file != file);
+        let is_new_file = last_file.as_ref().is_none_or(|last_file| last_file != file);
 
         if !is_new_file {
             return;
@@ -139,54 +640,286 @@ impl MessageWriter {
         *last_file = Some(file.to_owned());
     }
 
-    pub fn print_test_post(&self, test_case: &str, result: TestResult) {
-        if self.quiet {
+    pub fn print_test_post(&self, test: &impl Case, result: TestResult) {
+        if self.is_silenced() {
+            return;
+        }
+        let test_case = test.get_case_display_name();
+        if let Some(line) = &self.dots_line {
+            let dot = match result.outcome {
+                CaseOutcome::Passed => '.',
+                CaseOutcome::Failed => 'F',
+                CaseOutcome::Skipped => 'S',
+            };
+            self.print_dot(&mut line.borrow_mut(), dot);
+            return;
+        }
+        if self.only_failures && result.outcome != CaseOutcome::Failed {
             return;
         }
         let outcome = if let Some(err) = result.error {
-            format!("{outcome}:\n{err}", outcome = result.outcome)
+            format!(
+                "{outcome}:\n{err}",
+                outcome = result.outcome,
+                err = self.truncate(&err.to_string())
+            )
         } else {
             format!("{outcome}", outcome = result.outcome)
         };
 
-        if self.to_godot {
+        if self.only_failures {
+            // Nothing was printed before running the test, so the entry needs to identify it fully.
+            self.println(&format!(
+                "   -- {}::{test_case} ... {outcome}",
+                extract_file_subtitle(test.get_case_file())
+            ));
+            return;
+        }
+
+        if !self.appends_lines() {
             // For printing from godot, always print the whole line, as `print_test_pre` didn't print anything for the case.
             self.println(&format!("   -- {test_case} ... {outcome}"));
         } else {
             // Otherwise just outcome on same line.
-            println!("{outcome}");
+            self.println_console(&outcome.to_string());
+            self.println_file(&format!("   -- {test_case} ... {outcome}"));
         }
     }
 
     pub fn print_bench_pre(&self, benchmark: &impl Case, last_file: &mut Option<String>) {
-        if self.quiet {
+        if self.is_silenced() {
             return;
         }
         self.print_file_header(benchmark.get_case_file(), last_file);
 
         // If printing to Godot console, the result will be printed as a whole string. That's because every `godot_print!` prints
         // the whole line only and there is no alternative for appending to the godot console output.
-        if self.to_godot {
+        if !self.appends_lines() {
             return;
         }
-        let benchmark_name = if benchmark.get_case_name().len() > 26 {
-            &benchmark.get_case_name()[..26]
-        } else {
-            benchmark.get_case_name()
+        // Truncated by characters, as display names aren't limited to ASCII.
+        let benchmark_name = benchmark
+            .get_case_display_name()
+            .chars()
+            .take(26)
+            .collect::<String>();
+
+        self.print_console_part(&format!("   -- {benchmark_name:<26} ..."));
+    }
+
+    pub fn print_report_delta(&self, path: &str, delta: &ReportDelta) {
+        self.println("");
+        self.println(&format!(
+            "   Compared to '{path}': +{} passing, -{} newly failing",
+            delta.newly_passing.len(),
+            delta.newly_failing.len()
+        ));
+        for (label, cases) in [
+            ("Newly failing:", &delta.newly_failing),
+            ("Newly passing:", &delta.newly_passing),
+            ("Only in current report:", &delta.added),
+            ("Only in previous report:", &delta.removed),
+        ] {
+            if cases.is_empty() {
+                continue;
+            }
+            self.println(&format!("   {label}"));
+            for case in cases {
+                self.println(&format!("     * {case}"));
+            }
+        }
+        if !delta.slower.is_empty() {
+            self.println("   warning: slower than in previous report:");
+            for (case, previous, current) in &delta.slower {
+                self.println(&format!(
+                    "     * {case}: {} -> {}",
+                    self.format_duration(*previous, TimeUnit::Millis),
+                    self.format_duration(*current, TimeUnit::Millis)
+                ));
+            }
+        }
+    }
+
+    fn truncate(&self, message: &str) -> String {
+        let len = message.chars().count();
+        if self.max_message_len == 0 || len <= self.max_message_len {
+            return message.to_owned();
+        }
+        let truncated = message
+            .chars()
+            .take(self.max_message_len)
+            .collect::<String>();
+        format!(
+            "{truncated}... (truncated, {} more chars)",
+            len - self.max_message_len
+        )
+    }
+
+    /// Prints histogram of benchmark run times, with bars scaled to the width of every sink.
+    pub fn print_bench_histogram(&self, samples: &[Duration]) {
+        const MAX_BUCKETS: usize = 10;
+        // Width of the `start - endμs count |` prefix of every line.
+        const LABEL_WIDTH: usize = 38;
+
+        let (Some(min), Some(max)) = (samples.iter().min(), samples.iter().max()) else {
+            return;
         };
+        if min == max || samples.len() < 2 {
+            self.println(&format!(
+                "      all {} samples: {:.3}μs",
+                samples.len(),
+                min.as_nanos() as f64 / 1000.0
+            ));
+            return;
+        }
+
+        let buckets_count = samples.len().min(MAX_BUCKETS);
+        let min_nanos = min.as_nanos() as f64;
+        let bucket_nanos = (max.as_nanos() as f64 - min_nanos) / buckets_count as f64;
+        let mut buckets = vec![0usize; buckets_count];
+        for sample in samples {
+            let bucket = ((sample.as_nanos() as f64 - min_nanos) / bucket_nanos) as usize;
+            buckets[bucket.min(buckets_count - 1)] += 1;
+        }
+        let highest = buckets.iter().copied().max().unwrap_or(1);
+
+        self.println_rendered(|width| {
+            let bar_width = width.saturating_sub(LABEL_WIDTH).max(1);
+            buckets
+                .iter()
+                .enumerate()
+                .map(|(i, count)| {
+                    let start = (min_nanos + bucket_nanos * i as f64) / 1000.0;
+                    let end = (min_nanos + bucket_nanos * (i + 1) as f64) / 1000.0;
+                    let bar = "#".repeat((count * bar_width).div_ceil(highest));
+                    format!("      {start:>10.3} - {end:>10.3}μs {count:>4} |{bar}")
+                })
+                .collect::<Vec<_>>()
+                .join("\n")
+        });
+    }
+
+    /// Prints everything known about a single test, for `--describe`. The outcome is taken from the `--compare-report` report,
+    /// given as its path and the case found in it.
+    pub fn print_test_description(
+        &self,
+        test: &RustTestCase,
+        suite_notes: &[String],
+        previous: Option<(&str, Option<&CaseReport>)>,
+    ) {
+        let mut lines = vec![format!("   {}", test.full_name())];
+        let mut field = |label: &str, value: String| lines.push(format!("   {label:<14} {value}"));
+
+        let display_name = test.get_case_display_name();
+        if display_name != test.name {
+            field("display name:", display_name);
+        }
+        field("location:", format!("{}:{}", test.file, test.line));
+        if let Some(description) = test.description {
+            field("description:", description.to_owned());
+        }
+        let mut tags = Vec::new();
+        if let Some(keyword) = test.keyword {
+            tags.push(format!("keyword '{keyword}'"));
+        }
+        for (is_set, tag) in [
+            (test.smoke, "smoke"),
+            (test.focused, "focused"),
+            (test.skipped, "skipped"),
+        ] {
+            if is_set {
+                tags.push(tag.to_owned());
+            }
+        }
+        if !tags.is_empty() {
+            field("tags:", tags.join(", "));
+        }
+        if test.priority != 0 {
+            field("priority:", test.priority.to_string());
+        }
+        if let Some(timeout) = test.timeout {
+            field("timeout:", format!("{timeout}s"));
+        }
+        if let Some(retries) = test.retries {
+            field("retries:", retries.to_string());
+        }
+        if let Some(mode) = test.requires {
+            field("requires:", mode.to_string());
+        }
+        if !test.depends_on.is_empty() {
+            field("depends on:", test.depends_on.join(", "));
+        }
+        if let Some(scene_path) = test.scene_path {
+            field("scene path:", scene_path.to_owned());
+        }
+        for note in suite_notes {
+            field("suite:", note.clone());
+        }
+        match previous {
+            Some((path, Some(case))) => {
+                let outcome = match case.outcome {
+                    ReportOutcome::Passed => "passed",
+                    ReportOutcome::Failed => "failed",
+                    ReportOutcome::Skipped => "skipped",
+                };
+                let duration = self.format_duration(case.duration(), TimeUnit::Seconds);
+                field("last outcome:", format!("{outcome} in {duration} ({path})"));
+                if let Some(message) = case
+                    .message
+                    .as_deref()
+                    .filter(|_| case.outcome == ReportOutcome::Failed)
+                {
+                    lines.push(self.truncate(message));
+                }
+            }
+            Some((path, None)) => field("last outcome:", format!("not in {path}")),
+            None => {}
+        }
+
+        for line in lines {
+            self.println(&line);
+        }
+    }
+
+    /// Prints every suite with the counts of its tests and benchmarks, for `--list-suites`.
+    pub fn print_suites_listing(&self, suites: &[(&str, usize, usize)]) {
+        for (suite, tests, benches) in suites {
+            self.println(&format!(
+                "   {suite:<30} {tests:>5} tests {benches:>5} benchmarks"
+            ));
+        }
+    }
+
+    /// Prints medians of grouped benchmarks relative to the fastest one in every group.
+    pub fn print_bench_groups(&self, benches: &[(&str, &str, Duration)]) {
+        let mut groups: Vec<(&str, Vec<(&str, Duration)>)> = Vec::new();
+        for (group, name, median) in benches {
+            match groups.iter_mut().find(|(existing, _)| existing == group) {
+                Some((_, members)) => members.push((name, *median)),
+                None => groups.push((group, vec![(name, *median)])),
+            }
+        }
 
-        print!("   -- {benchmark_name:<26} ...");
+        for (group, mut members) in groups {
+            members.sort_by_key(|(_, median)| *median);
+            let fastest = members[0].1.as_nanos().max(1) as f64;
+            self.println(&format!("   Relative speed in group '{group}':"));
+            for (name, median) in members {
+                self.println(&format!(
+                    "   * {name:<26} {:>12.3}μs {:>8.2}x",
+                    median.as_nanos() as f64 / 1000.0,
+                    median.as_nanos() as f64 / fastest
+                ));
+            }
+            self.println("");
+        }
     }
 
     pub fn print_bench_post(&self, benchmark: &str, result: BenchResult) {
-        if self.quiet {
+        if self.is_silenced() {
             return;
         }
-        let adjusted_name = if benchmark.len() > 26 {
-            &benchmark[..26]
-        } else {
-            benchmark
-        };
+        let adjusted_name = benchmark.chars().take(26).collect::<String>();
 
         let outcome = match &result.outcome {
             CaseOutcome::Passed => {
@@ -194,24 +927,113 @@ impl MessageWriter {
                 for stat in result.stats.iter() {
                     outcome.push_str(&format!(" {:>10.3}μs", stat.as_nanos() as f64 / 1000.0));
                 }
+                if let Some(comparison) = &result.comparison {
+                    outcome.push_str(&format!(" {comparison}"));
+                }
                 outcome
             }
             CaseOutcome::Failed => {
                 format!(
                     "    {outcome}:\n{err}",
                     outcome = result.outcome,
-                    err = result.error.expect("couldn't unwrap error")
+                    err = self.truncate(&result.error.expect("couldn't unwrap error").to_string())
                 )
             }
             _ => format!("    {}", result.outcome),
         };
 
-        if self.to_godot {
+        if !self.appends_lines() {
             // For printing from godot, always print the whole line, as `print_test_pre` didn't print anything for the case.
             self.println(&format!("   -- {adjusted_name:<26} ...{outcome}"));
         } else {
             // Otherwise just outcome on same line.
-            println!("{outcome\n}");
+            self.println_console(&outcome);
+            self.println_file(&format!("   -- {adjusted_name:<26} ...{outcome}"));
         }
     }
 }
+
+/// Splits `message` into lines no longer than `width`, breaking only on whitespace.
+fn wrap_words(message: &str, width: usize) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut current = String::new();
+    for word in message.split_whitespace() {
+        if !current.is_empty() && current.chars().count() + 1 + word.chars().count() > width {
+            lines.push(std::mem::take(&mut current));
+        }
+        if !current.is_empty() {
+            current.push(' ');
+        }
+        current.push_str(word);
+    }
+    lines.push(current);
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+    use std::sync::{Arc, Mutex};
+
+    use super::{MessageWriter, DEFAULT_WIDTH};
+
+    type Console = Rc<RefCell<Vec<u8>>>;
+    type File = Arc<Mutex<Vec<u8>>>;
+
+    fn writer(quiet: bool, width: usize) -> (MessageWriter, Console, File) {
+        let console = Console::default();
+        let file = File::default();
+        let writer = MessageWriter::with_sinks(quiet, width, console.clone(), file.clone());
+        (writer, console, file)
+    }
+
+    fn lines(output: &[u8]) -> Vec<String> {
+        String::from_utf8(output.to_vec())
+            .unwrap()
+            .lines()
+            .map(str::to_owned)
+            .collect()
+    }
+
+    const LONG_MESSAGE: &str =
+        "Began run in headless mode in scene: res://tests/scenes/runner_scene.tscn";
+
+    #[test]
+    fn file_sink_keeps_default_width_while_narrow_console_wraps() {
+        let (writer, console, file) = writer(false, 40);
+        writer.println_centered(LONG_MESSAGE);
+        writer.print_horizontal_separator();
+
+        let file = lines(&file.lock().unwrap());
+        assert_eq!(file.len(), 2);
+        assert_eq!(file[0].trim(), LONG_MESSAGE);
+        assert_eq!(file[1].chars().count(), DEFAULT_WIDTH);
+
+        let console = lines(&console.borrow());
+        assert!(console.len() > 2, "console didn't wrap: {console:?}");
+        assert!(console.iter().all(|line| line.chars().count() <= 40));
+        assert_eq!(console.last().unwrap().chars().count(), 40);
+    }
+
+    #[test]
+    fn quiet_silences_only_console() {
+        let (writer, console, file) = writer(true, 40);
+        writer.println("in file only");
+
+        assert!(lines(&console.borrow()).is_empty());
+        assert_eq!(lines(&file.lock().unwrap()), ["in file only"]);
+    }
+
+    #[test]
+    fn detached_writer_mirrors_to_file_from_other_thread() {
+        let (writer, console, file) = writer(true, 40);
+        let detached = writer.detached();
+        std::thread::spawn(move || detached.println("from other thread"))
+            .join()
+            .unwrap();
+
+        assert!(lines(&console.borrow()).is_empty());
+        assert_eq!(lines(&file.lock().unwrap()), ["from other thread"]);
+    }
+}
@@ -0,0 +1,1100 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::io::Write;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use godot::builtin::Dictionary;
+use serde::{Deserialize, Serialize};
+
+use crate::cases::{Case, CaseOutcome, CaseType, FailureKind};
+
+use super::config::{ConfigError, ConfigErrorKind, RunnerConfig};
+use super::glob::GlobPattern;
+use super::print::MessageWriter;
+use super::{extract_file_subtitle, relative_path};
+
+/// Version of the runner crate, included in the run information and reports.
+pub(crate) const RUNNER_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// Format of the report emitted to the console after the run, selected with `--format`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum ReportFormat {
+    Json,
+    Junit,
+    /// One character per test instead of one line, printed during the run.
+    Dots,
+}
+
+impl ReportFormat {
+    pub fn from_arg(value: &str) -> Option<Self> {
+        match value.to_lowercase().as_str() {
+            "json" => Some(Self::Json),
+            "junit" => Some(Self::Junit),
+            "dots" => Some(Self::Dots),
+            _ => None,
+        }
+    }
+}
+
+impl Display for ReportFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReportFormat::Json => f.write_str("json"),
+            ReportFormat::Junit => f.write_str("junit"),
+            ReportFormat::Dots => f.write_str("dots"),
+        }
+    }
+}
+
+/// Kind of the reported case.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ReportCaseKind {
+    Test,
+    Bench,
+}
+
+impl From<CaseType> for ReportCaseKind {
+    fn from(kind: CaseType) -> Self {
+        match kind {
+            CaseType::RustTest => Self::Test,
+            CaseType::RustBenchmark => Self::Bench,
+        }
+    }
+}
+
+/// Outcome of the reported case.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ReportOutcome {
+    Passed,
+    Failed,
+    Skipped,
+}
+
+impl From<&CaseOutcome> for ReportOutcome {
+    fn from(outcome: &CaseOutcome) -> Self {
+        match outcome {
+            CaseOutcome::Passed => Self::Passed,
+            CaseOutcome::Failed => Self::Failed,
+            CaseOutcome::Skipped => Self::Skipped,
+        }
+    }
+}
+
+/// Result of a single executed case, as seen by the reporters.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct CaseReport {
+    pub kind: ReportCaseKind,
+    pub file: String,
+    pub name: String,
+    /// Name shown instead of `name`, if it differs from it. Added in schema version `1.3`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+    pub outcome: ReportOutcome,
+    pub duration_ns: u64,
+    pub failure_kind: Option<FailureKind>,
+    pub message: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub sub_failures: Vec<String>,
+    /// Median run time, only for passed benchmarks.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub median_ns: Option<u64>,
+}
+
+impl CaseReport {
+    pub(crate) fn new(
+        kind: CaseType,
+        case: &impl Case,
+        outcome: &CaseOutcome,
+        duration: Duration,
+        failure_kind: Option<FailureKind>,
+        message: Option<String>,
+    ) -> Self {
+        Self {
+            kind: kind.into(),
+            file: extract_file_subtitle(case.get_case_file()).to_owned(),
+            name: case.get_case_name().to_owned(),
+            display_name: Some(case.get_case_display_name())
+                .filter(|display_name| display_name != case.get_case_name()),
+            outcome: outcome.into(),
+            duration_ns: duration.as_nanos() as u64,
+            failure_kind,
+            message,
+            sub_failures: Vec::new(),
+            median_ns: None,
+        }
+    }
+
+    pub(crate) fn with_sub_failures(mut self, sub_failures: &[String]) -> Self {
+        self.sub_failures = sub_failures.to_vec();
+        self
+    }
+
+    pub(crate) fn with_median(mut self, median: Duration) -> Self {
+        self.median_ns = Some(median.as_nanos() as u64);
+        self
+    }
+
+    pub fn duration(&self) -> Duration {
+        Duration::from_nanos(self.duration_ns)
+    }
+
+    /// Name shown in the output, which is the canonical `name` unless the case has a different display name.
+    pub fn display_name(&self) -> &str {
+        self.display_name.as_deref().unwrap_or(&self.name)
+    }
+
+    /// Identifies the case across reports by its kind, file and name.
+    pub(crate) fn key(&self) -> String {
+        let suffix = match self.kind {
+            ReportCaseKind::Test => "",
+            ReportCaseKind::Bench => " (benchmark)",
+        };
+        format!("{}::{}{suffix}", self.file, self.name)
+    }
+}
+
+/// Results of the whole run, consumed by every [`Reporter`].
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct RunReport {
+    /// Version of the report schema in `major.minor` format, see [`RunReport::SCHEMA_VERSION`].
+    #[serde(default = "RunReport::first_schema_version")]
+    pub schema_version: String,
+    /// Version of the runner which produced the report, empty for reports without it. Added in schema version `1.2`.
+    #[serde(default)]
+    pub runner_version: String,
+    pub success: bool,
+    /// Reason why no case was executed, e.g. failed `before_run` hook.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub aborted: Option<String>,
+    /// Start of the run, as UTC timestamp in `YYYY-MM-DDTHH:MM:SS` format. Added in schema version `1.1`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub started_at: Option<String>,
+    pub duration_ns: u64,
+    pub meta: BTreeMap<String, String>,
+    pub cases: Vec<CaseReport>,
+}
+
+impl Default for RunReport {
+    fn default() -> Self {
+        Self {
+            schema_version: Self::SCHEMA_VERSION.to_owned(),
+            runner_version: RUNNER_VERSION.to_owned(),
+            success: false,
+            aborted: None,
+            started_at: None,
+            duration_ns: 0,
+            meta: BTreeMap::new(),
+            cases: Vec::new(),
+        }
+    }
+}
+
+impl RunReport {
+    /// Current version of the report schema, in `major.minor` format.
+    ///
+    /// Major version is bumped on breaking changes, like removed or renamed fields, and minor version when new fields are
+    /// added. Reports with different major version are rejected by `--render-report` and `--compare-report`.
+    pub const SCHEMA_VERSION: &'static str = "1.3";
+
+    /// Version assumed for reports written before the version was included in them.
+    fn first_schema_version() -> String {
+        "1.0".to_owned()
+    }
+
+    fn schema_major(version: &str) -> Option<&str> {
+        let (major, minor) = version.split_once('.')?;
+        let is_number = |part: &str| !part.is_empty() && part.chars().all(|c| c.is_ascii_digit());
+        (is_number(major) && is_number(minor)).then_some(major)
+    }
+
+    pub(crate) fn add_case(&mut self, case: CaseReport) {
+        self.cases.push(case);
+    }
+
+    pub(crate) fn finish(&mut self, success: bool, duration: Duration, config: &RunnerConfig) {
+        self.success = success;
+        self.started_at = SystemTime::now().checked_sub(duration).map(utc_timestamp);
+        self.duration_ns = duration.as_nanos() as u64;
+        self.meta = config.meta().iter().cloned().collect();
+    }
+
+    pub fn duration(&self) -> Duration {
+        Duration::from_nanos(self.duration_ns)
+    }
+
+    /// Loads the report previously written by [`JsonReporter`].
+    ///
+    /// Schema version is checked before the rest of the report, so incompatible reports are rejected with a clear message.
+    pub(crate) fn load(path: &str) -> Result<Self, ConfigError> {
+        let file_error = |message| ConfigError::of_kind(ConfigErrorKind::File, &[], message);
+        let content = std::fs::read_to_string(path)
+            .map_err(|err| file_error(format!("couldn't read report '{path}': {err}")))?;
+        let malformed =
+            |err: serde_json::Error| file_error(format!("malformed report '{path}': {err}"));
+        let value = serde_json::from_str::<serde_json::Value>(&content).map_err(malformed)?;
+
+        let version = match value.get("schema_version") {
+            None => Self::first_schema_version(),
+            Some(version) => version.as_str().unwrap_or_default().to_owned(),
+        };
+        let Some(major) = Self::schema_major(&version) else {
+            return Err(file_error(format!(
+                "malformed report '{path}': invalid schema version: {}",
+                value["schema_version"]
+            )));
+        };
+        let supported = Self::schema_major(Self::SCHEMA_VERSION).unwrap_or_default();
+        if major != supported {
+            return Err(file_error(format!(
+                "report '{path}' uses incompatible schema version {version}, supported versions are {supported}.x"
+            )));
+        }
+        serde_json::from_value(value).map_err(malformed)
+    }
+
+    /// Loads and merges the reports of sharded runs, selected with `--merge-reports`.
+    ///
+    /// Every entry is either a path or a glob pattern in its file name, e.g. `reports/shard-*.json`. Cases are concatenated in the
+    /// order of the reports. As shards run side by side, the merged run started with the earliest of them and lasted as long as
+    /// the longest one.
+    pub(crate) fn merge(entries: &[String]) -> Result<MergedReports, ConfigError> {
+        let mut paths = Vec::new();
+        for entry in entries {
+            paths.extend(Self::expand_report_glob(entry)?);
+        }
+
+        let mut merged = MergedReports {
+            report: RunReport {
+                success: true,
+                ..Default::default()
+            },
+            paths,
+            duplicates: Vec::new(),
+        };
+        let mut seen = BTreeMap::new();
+        let mut aborted = Vec::new();
+        for path in &merged.paths {
+            let report = Self::load(path)?;
+            let merged_report = &mut merged.report;
+            merged_report.success &= report.success;
+            if let Some(reason) = report.aborted {
+                aborted.push(format!("{path}: {reason}"));
+            }
+            merged_report.started_at = match (merged_report.started_at.take(), report.started_at) {
+                (Some(merged), Some(started)) => Some(merged.min(started)),
+                (merged, started) => merged.or(started),
+            };
+            merged_report.duration_ns = merged_report.duration_ns.max(report.duration_ns);
+            for (key, value) in report.meta {
+                merged_report.meta.entry(key).or_insert(value);
+            }
+            for case in report.cases {
+                if let Some(first_path) = seen.insert(case.key(), path.clone()) {
+                    merged
+                        .duplicates
+                        .push(format!("{} (in {first_path} and {path})", case.key()));
+                }
+                merged_report.cases.push(case);
+            }
+        }
+        if !aborted.is_empty() {
+            merged.report.aborted = Some(aborted.join("; "));
+        }
+        Ok(merged)
+    }
+
+    fn expand_report_glob(entry: &str) -> Result<Vec<String>, ConfigError> {
+        let (dir, name) = match entry.rfind(['/', '\\']) {
+            Some(sep_pos) => (&entry[..sep_pos + 1], &entry[sep_pos + 1..]),
+            None => ("", entry),
+        };
+        if !name.contains(['*', '?', '[']) {
+            return Ok(vec![entry.to_owned()]);
+        }
+
+        let file_error = |message| ConfigError::of_kind(ConfigErrorKind::File, &[], message);
+        let pattern = GlobPattern::parse(name).map_err(file_error)?;
+        let read_dir =
+            std::fs::read_dir(if dir.is_empty() { "." } else { dir }).map_err(|err| {
+                file_error(format!("couldn't list reports matching '{entry}': {err}"))
+            })?;
+        let mut paths = read_dir
+            .filter_map(|dir_entry| dir_entry.ok())
+            .filter(|dir_entry| dir_entry.path().is_file())
+            .filter_map(|dir_entry| dir_entry.file_name().into_string().ok())
+            .filter(|file_name| pattern.matches(file_name))
+            .map(|file_name| format!("{dir}{file_name}"))
+            .collect::<Vec<_>>();
+        if paths.is_empty() {
+            return Err(file_error(format!("no reports match '{entry}'")));
+        }
+        paths.sort();
+        Ok(paths)
+    }
+
+    fn count(&self, outcome: ReportOutcome) -> usize {
+        self.cases.iter().filter(|c| c.outcome == outcome).count()
+    }
+
+    pub fn counts(&self) -> RunCounts {
+        RunCounts {
+            passed: self.count(ReportOutcome::Passed),
+            failed: self.count(ReportOutcome::Failed),
+            skipped: self.count(ReportOutcome::Skipped),
+            timed_out: self
+                .cases
+                .iter()
+                .filter(|c| c.failure_kind == Some(FailureKind::Timeout))
+                .count(),
+        }
+    }
+}
+
+/// Report merged from the reports of sharded runs by [`RunReport::merge`].
+pub(crate) struct MergedReports {
+    pub report: RunReport,
+    /// Loaded reports, with glob patterns expanded.
+    pub paths: Vec<String>,
+    /// Cases present in more than one report, with the reports they were found in.
+    pub duplicates: Vec<String>,
+}
+
+/// Differences between the outcomes of the previous and the current run, selected with `--compare-report`.
+///
+/// Cases are identified by their kind, file and name.
+#[derive(Default)]
+pub(crate) struct ReportDelta {
+    pub newly_failing: Vec<String>,
+    pub newly_passing: Vec<String>,
+    /// Cases present only in the current report.
+    pub added: Vec<String>,
+    /// Cases present only in the previous report.
+    pub removed: Vec<String>,
+    /// Tests passing in both reports, slower than in the previous one by more than `--perf-regression-pct`:
+    /// their previous and current durations.
+    pub slower: Vec<(String, Duration, Duration)>,
+}
+
+impl ReportDelta {
+    /// Tests faster than this in the previous run are too noisy to be checked for performance regressions.
+    const PERF_REGRESSION_MIN_DURATION: Duration = Duration::from_millis(1);
+
+    pub fn new(
+        previous: &RunReport,
+        current: &RunReport,
+        perf_regression_pct: Option<f64>,
+    ) -> Self {
+        let previous_outcomes = previous
+            .cases
+            .iter()
+            .map(|case| (case.key(), case.outcome))
+            .collect::<BTreeMap<_, _>>();
+        let current_outcomes = current
+            .cases
+            .iter()
+            .map(|case| (case.key(), case.outcome))
+            .collect::<BTreeMap<_, _>>();
+
+        let mut delta = Self::default();
+        for (key, outcome) in &current_outcomes {
+            match (previous_outcomes.get(key), outcome) {
+                (None, _) => delta.added.push(key.clone()),
+                (Some(ReportOutcome::Failed), ReportOutcome::Passed) => {
+                    delta.newly_passing.push(key.clone())
+                }
+                (Some(previous), ReportOutcome::Failed) if *previous != ReportOutcome::Failed => {
+                    delta.newly_failing.push(key.clone())
+                }
+                _ => {}
+            }
+        }
+        delta.removed = previous_outcomes
+            .into_keys()
+            .filter(|key| !current_outcomes.contains_key(key))
+            .collect();
+
+        if let Some(pct) = perf_regression_pct {
+            let passed_tests = |report: &RunReport| {
+                report
+                    .cases
+                    .iter()
+                    .filter(|case| {
+                        case.kind == ReportCaseKind::Test && case.outcome == ReportOutcome::Passed
+                    })
+                    .map(|case| (case.key(), case.duration()))
+                    .collect::<BTreeMap<_, _>>()
+            };
+            let previous_durations = passed_tests(previous);
+            for (key, current) in passed_tests(current) {
+                let Some(&previous) = previous_durations.get(&key) else {
+                    continue;
+                };
+                if previous < Self::PERF_REGRESSION_MIN_DURATION {
+                    continue;
+                }
+                let delta_pct = (current.as_secs_f64() - previous.as_secs_f64())
+                    / previous.as_secs_f64()
+                    * 100.;
+                if delta_pct > pct {
+                    delta.slower.push((key, previous, current));
+                }
+            }
+        }
+        delta
+    }
+}
+
+/// Compact counts of case outcomes for the whole run.
+///
+/// `timed_out` cases are also included in `failed`.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct RunCounts {
+    pub passed: usize,
+    pub failed: usize,
+    pub skipped: usize,
+    pub timed_out: usize,
+}
+
+impl RunCounts {
+    pub fn to_dictionary(self) -> Dictionary {
+        let mut dict = Dictionary::new();
+        dict.set("passed", self.passed as i64);
+        dict.set("failed", self.failed as i64);
+        dict.set("skipped", self.skipped as i64);
+        dict.set("timed_out", self.timed_out as i64);
+        dict
+    }
+}
+
+/// Destination of the rendered report.
+#[derive(Clone, Debug)]
+pub(crate) enum ReportTarget {
+    Stdout,
+    File(String),
+}
+
+impl ReportTarget {
+    fn write(&self, content: &str) -> std::io::Result<()> {
+        match self {
+            ReportTarget::Stdout => {
+                let mut stdout = std::io::stdout().lock();
+                writeln!(stdout, "{content}")
+            }
+            ReportTarget::File(path) => std::fs::write(path, content),
+        }
+    }
+}
+
+impl Display for ReportTarget {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReportTarget::Stdout => f.write_str("stdout"),
+            ReportTarget::File(path) => write!(f, "'{path}'"),
+        }
+    }
+}
+
+/// Sink consuming the results of the run.
+///
+/// Custom reporters can be registered with [`GdTestRunner::add_reporter`](crate::runner::GdTestRunner::add_reporter) before the run
+/// starts. All methods have default empty implementations, so only the needed ones have to be implemented.
+pub trait Reporter {
+    /// Name of the reporter, used in error messages.
+    fn name(&self) -> String {
+        "custom reporter".to_owned()
+    }
+
+    /// Called once, before any case is executed.
+    fn on_run_start(&mut self) {}
+
+    /// Called after every executed test and benchmark.
+    fn on_test_finish(&mut self, _case: &CaseReport) {}
+
+    /// Called once, after all cases were executed. Returned error fails the run.
+    fn on_run_finish(&mut self, _report: &RunReport) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+pub(crate) struct JsonReporter {
+    target: ReportTarget,
+}
+
+impl JsonReporter {
+    pub fn new(target: ReportTarget) -> Self {
+        Self { target }
+    }
+
+    pub fn render(report: &RunReport) -> String {
+        // Serializing plain data structure can't fail.
+        serde_json::to_string_pretty(report).unwrap_or_default()
+    }
+}
+
+impl Reporter for JsonReporter {
+    fn name(&self) -> String {
+        format!("JSON report to {}", self.target)
+    }
+
+    fn on_run_finish(&mut self, report: &RunReport) -> std::io::Result<()> {
+        self.target.write(&Self::render(report))
+    }
+}
+
+pub(crate) struct JunitReporter {
+    target: ReportTarget,
+}
+
+impl JunitReporter {
+    pub fn new(target: ReportTarget) -> Self {
+        Self { target }
+    }
+
+    pub fn render(report: &RunReport) -> String {
+        // Every source file of every case kind is a separate suite, in order of execution.
+        let mut suites: Vec<(String, Vec<&CaseReport>)> = Vec::new();
+        for case in &report.cases {
+            let suite_name = match case.kind {
+                ReportCaseKind::Test => case.file.clone(),
+                ReportCaseKind::Bench => format!("{} (benchmarks)", case.file),
+            };
+            match suites.iter_mut().find(|(name, _)| *name == suite_name) {
+                Some((_, cases)) => cases.push(case),
+                None => suites.push((suite_name, vec![case])),
+            }
+        }
+
+        let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        xml.push_str(&format!(
+            "<testsuites name=\"gd-rehearse\" tests=\"{}\" failures=\"{}\" skipped=\"{}\" time=\"{:.6}\">\n",
+            report.cases.len(),
+            report.count(ReportOutcome::Failed),
+            report.count(ReportOutcome::Skipped),
+            report.duration().as_secs_f64()
+        ));
+
+        // Reports saved before the start of the run was recorded have no timestamp.
+        let timestamp = report
+            .started_at
+            .as_ref()
+            .map(|started_at| format!(" timestamp=\"{}\"", xml_escape(started_at)))
+            .unwrap_or_default();
+
+        for (suite_name, cases) in suites {
+            let count = |outcome| cases.iter().filter(|c| c.outcome == outcome).count();
+            let time = cases.iter().map(|c| c.duration()).sum::<Duration>();
+            xml.push_str(&format!(
+                "  <testsuite name=\"{}\" tests=\"{}\" failures=\"{}\" skipped=\"{}\" time=\"{:.6}\"{timestamp}>\n",
+                xml_escape(&suite_name),
+                cases.len(),
+                count(ReportOutcome::Failed),
+                count(ReportOutcome::Skipped),
+                time.as_secs_f64()
+            ));
+            xml.push_str(&format!(
+                "    <properties>\n      <property name=\"schema_version\" value=\"{}\"/>\n      <property name=\"runner_version\" value=\"{}\"/>\n    </properties>\n",
+                xml_escape(&report.schema_version),
+                xml_escape(&report.runner_version)
+            ));
+            for case in cases {
+                xml.push_str(&Self::render_case(&suite_name, case));
+            }
+            xml.push_str("  </testsuite>\n");
+        }
+
+        xml.push_str("</testsuites>");
+        xml
+    }
+
+    fn render_case(suite_name: &str, case: &CaseReport) -> String {
+        let open = format!(
+            "    <testcase name=\"{}\" classname=\"{}\" time=\"{:.6}\"",
+            xml_escape(case.display_name()),
+            xml_escape(suite_name),
+            case.duration().as_secs_f64()
+        );
+        match case.outcome {
+            ReportOutcome::Passed => format!("{open}/>\n"),
+            ReportOutcome::Skipped => format!("{open}>\n      <skipped/>\n    </testcase>\n"),
+            ReportOutcome::Failed => {
+                let message = case.message.as_deref().unwrap_or_default();
+                let summary = message.lines().next().unwrap_or_default();
+                format!(
+                    "{open}>\n      <failure type=\"{}\" message=\"{}\">{}</failure>\n    </testcase>\n",
+                    xml_escape(&case.failure_kind.map_or("failure".to_owned(), |kind| kind.to_string())),
+                    xml_escape(summary),
+                    xml_escape(message)
+                )
+            }
+        }
+    }
+}
+
+impl Reporter for JunitReporter {
+    fn name(&self) -> String {
+        format!("JUnit report to {}", self.target)
+    }
+
+    fn on_run_finish(&mut self, report: &RunReport) -> std::io::Result<()> {
+        self.target.write(&Self::render(report))
+    }
+}
+
+/// Formats the time as `YYYY-MM-DDTHH:MM:SS` in UTC, as expected by JUnit `timestamp` attribute.
+fn utc_timestamp(time: SystemTime) -> String {
+    let secs = time
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |since| since.as_secs());
+    let (days, secs_of_day) = ((secs / 86400) as i64, secs % 86400);
+
+    // Civil date from days since 1970-01-01, see http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let day_of_era = z.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+    format!(
+        "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}",
+        secs_of_day / 3600,
+        secs_of_day / 60 % 60,
+        secs_of_day % 60
+    )
+}
+
+/// Minimal outcome of the run for badge generators, written with `--status-file`.
+#[derive(Serialize)]
+struct RunStatus {
+    status: &'static str,
+    passed: usize,
+    failed: usize,
+    skipped: usize,
+}
+
+pub(crate) struct StatusReporter {
+    target: ReportTarget,
+}
+
+impl StatusReporter {
+    pub fn new(target: ReportTarget) -> Self {
+        Self { target }
+    }
+
+    pub fn render(report: &RunReport) -> String {
+        let counts = report.counts();
+        let status = RunStatus {
+            status: if report.success { "passing" } else { "failing" },
+            passed: counts.passed,
+            failed: counts.failed,
+            skipped: counts.skipped,
+        };
+        // Serializing plain data structure can't fail.
+        serde_json::to_string(&status).unwrap_or_default()
+    }
+}
+
+impl Reporter for StatusReporter {
+    fn name(&self) -> String {
+        format!("status file {}", self.target)
+    }
+
+    fn on_run_finish(&mut self, report: &RunReport) -> std::io::Result<()> {
+        self.target.write(&Self::render(report))
+    }
+}
+
+pub(crate) struct CsvReporter {
+    target: ReportTarget,
+}
+
+impl CsvReporter {
+    pub fn new(target: ReportTarget) -> Self {
+        Self { target }
+    }
+
+    pub fn render(report: &RunReport) -> String {
+        let mut csv = String::from("name,suite,outcome,duration_ms,failure_kind,message\r\n");
+        for case in &report.cases {
+            // Benchmarks are kept apart from tests of the same file, as in the JUnit report.
+            let suite = match case.kind {
+                ReportCaseKind::Test => case.file.clone(),
+                ReportCaseKind::Bench => format!("{} (benchmarks)", case.file),
+            };
+            let outcome = match case.outcome {
+                ReportOutcome::Passed => "passed",
+                ReportOutcome::Failed => "failed",
+                ReportOutcome::Skipped => "skipped",
+            };
+            let fields = [
+                csv_escape(&case.name),
+                csv_escape(&suite),
+                outcome.to_owned(),
+                format!("{:.3}", case.duration().as_secs_f64() * 1000.),
+                csv_escape(
+                    &case
+                        .failure_kind
+                        .map(|kind| kind.to_string())
+                        .unwrap_or_default(),
+                ),
+                csv_escape(case.message.as_deref().unwrap_or_default()),
+            ];
+            csv.push_str(&fields.join(","));
+            csv.push_str("\r\n");
+        }
+        csv
+    }
+}
+
+impl Reporter for CsvReporter {
+    fn name(&self) -> String {
+        format!("CSV report to {}", self.target)
+    }
+
+    fn on_run_finish(&mut self, report: &RunReport) -> std::io::Result<()> {
+        self.target.write(&Self::render(report))
+    }
+}
+
+/// Quotes the field if needed, according to RFC 4180.
+fn csv_escape(field: &str) -> String {
+    if field.contains([',', '"', '\r', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_owned()
+    }
+}
+
+fn xml_escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            // Other control characters, like ANSI escapes in panic messages, aren't allowed in XML at all.
+            c if c < ' ' && !matches!(c, '\t' | '\n' | '\r') => {}
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Entry of the case inventory, printed with `--list-json`.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub(crate) struct CaseListing {
+    pub kind: ReportCaseKind,
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+    /// Source file without its path, as used for grouping in the results.
+    pub suite: String,
+    pub tags: Vec<String>,
+    pub file: String,
+    pub line: u32,
+    pub description: Option<String>,
+}
+
+impl CaseListing {
+    pub fn new(kind: CaseType, case: &impl Case, line: u32) -> Self {
+        Self {
+            kind: kind.into(),
+            name: case.get_case_name().to_owned(),
+            display_name: Some(case.get_case_display_name())
+                .filter(|display_name| display_name != case.get_case_name()),
+            suite: extract_file_subtitle(case.get_case_file()).to_owned(),
+            tags: case
+                .get_case_keyword()
+                .iter()
+                .map(|keyword| keyword.to_string())
+                .collect(),
+            file: relative_path(case.get_case_file()),
+            line,
+            description: case.get_case_description().map(str::to_owned),
+        }
+    }
+
+    pub fn render(listings: &[CaseListing]) -> String {
+        // Serializing plain data structure can't fail.
+        serde_json::to_string_pretty(listings).unwrap_or_default()
+    }
+}
+
+/// Human-readable console output.
+///
+/// Output of the cases themselves is streamed by [`MessageWriter`] while they run, as it starts before the case is finished.
+pub(crate) struct HumanReporter {
+    writer: MessageWriter,
+    banner: bool,
+}
+
+impl HumanReporter {
+    pub fn new(writer: MessageWriter, banner: bool) -> Self {
+        Self { writer, banner }
+    }
+}
+
+impl Reporter for HumanReporter {
+    fn name(&self) -> String {
+        "console output".to_owned()
+    }
+
+    fn on_run_start(&mut self) {
+        if self.banner {
+            self.writer.print_begin();
+        }
+    }
+
+    fn on_run_finish(&mut self, report: &RunReport) -> std::io::Result<()> {
+        if report.success {
+            self.writer.print_success()
+        } else {
+            self.writer.print_failure()
+        }
+        Ok(())
+    }
+}
+
+/// Creates all machine-readable reporters requested in the config.
+pub(crate) fn reporters(config: &RunnerConfig) -> Vec<Box<dyn Reporter>> {
+    let mut reporters: Vec<Box<dyn Reporter>> = Vec::new();
+    for format in config.report_formats() {
+        match format {
+            ReportFormat::Json => reporters.push(Box::new(JsonReporter::new(ReportTarget::Stdout))),
+            ReportFormat::Junit => {
+                reporters.push(Box::new(JunitReporter::new(ReportTarget::Stdout)))
+            }
+            // Printed by `MessageWriter` as the cases finish.
+            ReportFormat::Dots => {}
+        }
+    }
+    if let Some(path) = config.json_report() {
+        reporters.push(Box::new(JsonReporter::new(ReportTarget::File(
+            path.to_owned(),
+        ))));
+    }
+    if let Some(path) = config.junit_report() {
+        reporters.push(Box::new(JunitReporter::new(ReportTarget::File(
+            path.to_owned(),
+        ))));
+    }
+    if let Some(path) = config.csv_report() {
+        reporters.push(Box::new(CsvReporter::new(ReportTarget::File(
+            path.to_owned(),
+        ))));
+    }
+    if let Some(path) = config.status_file() {
+        reporters.push(Box::new(StatusReporter::new(ReportTarget::File(
+            path.to_owned(),
+        ))));
+    }
+    reporters
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+    use std::time::{Duration, UNIX_EPOCH};
+
+    use crate::cases::FailureKind;
+
+    use super::{
+        utc_timestamp, CaseReport, JunitReporter, ReportCaseKind, ReportOutcome, RunReport,
+    };
+
+    /// Element of the XML document, parsed strictly enough to reject malformed output.
+    #[derive(Debug, Default)]
+    struct Element {
+        name: String,
+        attributes: BTreeMap<String, String>,
+        text: String,
+        children: Vec<Element>,
+    }
+
+    impl Element {
+        fn attr(&self, name: &str) -> &str {
+            self.attributes
+                .get(name)
+                .unwrap_or_else(|| panic!("<{}> has no attribute {name}", self.name))
+        }
+
+        fn children(&self, name: &str) -> Vec<&Element> {
+            self.children.iter().filter(|c| c.name == name).collect()
+        }
+    }
+
+    fn unescape(text: &str) -> String {
+        assert!(!text.contains(['<', '>', '"']), "unescaped text: {text:?}");
+        let mut unescaped = String::new();
+        let mut rest = text;
+        while let Some(amp) = rest.find('&') {
+            unescaped.push_str(&rest[..amp]);
+            let semicolon = amp + rest[amp..].find(';').expect("unterminated entity");
+            unescaped.push(match &rest[amp + 1..semicolon] {
+                "amp" => '&',
+                "lt" => '<',
+                "gt" => '>',
+                "quot" => '"',
+                "apos" => '\'',
+                entity => panic!("unknown entity: {entity}"),
+            });
+            rest = &rest[semicolon + 1..];
+        }
+        unescaped.push_str(rest);
+        assert!(
+            unescaped
+                .chars()
+                .all(|c| c >= ' ' || matches!(c, '\t' | '\n' | '\r')),
+            "invalid XML character in {unescaped:?}"
+        );
+        unescaped
+    }
+
+    fn parse_tag(tag: &str) -> Element {
+        let (name, mut attributes) = tag.split_once(' ').unwrap_or((tag, ""));
+        let mut element = Element {
+            name: name.to_owned(),
+            ..Default::default()
+        };
+        while let Some((key, value)) = attributes.split_once("=\"") {
+            let (value, rest) = value.split_once('"').expect("unterminated attribute");
+            let previous = element
+                .attributes
+                .insert(key.trim().to_owned(), unescape(value));
+            assert!(previous.is_none(), "duplicate attribute {key} in <{name}>");
+            attributes = rest;
+        }
+        assert!(attributes.trim().is_empty(), "malformed tag <{tag}>");
+        element
+    }
+
+    fn parse(xml: &str) -> Element {
+        let mut rest = xml
+            .strip_prefix("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n")
+            .expect("missing XML declaration");
+        // Holds the root element.
+        let mut stack = vec![Element::default()];
+        while let Some(start) = rest.find('<') {
+            let text = unescape(&rest[..start]);
+            stack.last_mut().unwrap().text.push_str(&text);
+            let end = start + rest[start..].find('>').expect("unterminated tag");
+            let tag = &rest[start + 1..end];
+            rest = &rest[end + 1..];
+
+            if let Some(name) = tag.strip_prefix('/') {
+                let element = stack.pop().unwrap();
+                assert_eq!(element.name, name, "mismatched closing tag");
+                stack
+                    .last_mut()
+                    .expect("unopened tag")
+                    .children
+                    .push(element);
+            } else if let Some(tag) = tag.strip_suffix('/') {
+                stack.last_mut().unwrap().children.push(parse_tag(tag));
+            } else {
+                stack.push(parse_tag(tag));
+            }
+        }
+        assert!(rest.trim().is_empty(), "trailing content: {rest:?}");
+
+        let mut document = stack.pop().unwrap();
+        assert!(stack.is_empty(), "unclosed tag <{}>", document.name);
+        assert!(document.text.trim().is_empty());
+        assert_eq!(document.children.len(), 1, "expected single root element");
+        document.children.pop().unwrap()
+    }
+
+    fn case(kind: ReportCaseKind, name: &str, outcome: ReportOutcome) -> CaseReport {
+        CaseReport {
+            kind,
+            file: "tests/a&b.rs".to_owned(),
+            name: name.to_owned(),
+            display_name: None,
+            outcome,
+            duration_ns: 1_500_000,
+            failure_kind: None,
+            message: None,
+            sub_failures: Vec::new(),
+            median_ns: None,
+        }
+    }
+
+    #[test]
+    fn junit_report_parses_back() {
+        let message = "left: <1> & \"2\"\n\x1b[31mright: '3'\x1b[0m";
+        let report = RunReport {
+            started_at: Some("2024-02-29T12:34:56".to_owned()),
+            cases: vec![
+                case(ReportCaseKind::Test, "passed <test>", ReportOutcome::Passed),
+                CaseReport {
+                    failure_kind: Some(FailureKind::Assertion),
+                    message: Some(message.to_owned()),
+                    ..case(ReportCaseKind::Test, "failed_test", ReportOutcome::Failed)
+                },
+                case(ReportCaseKind::Test, "skipped_test", ReportOutcome::Skipped),
+                case(ReportCaseKind::Bench, "bench", ReportOutcome::Passed),
+            ],
+            ..Default::default()
+        };
+
+        let root = parse(&JunitReporter::render(&report));
+        assert_eq!(root.name, "testsuites");
+        assert_eq!(root.attr("tests"), "4");
+        assert_eq!(root.attr("failures"), "1");
+        assert_eq!(root.attr("skipped"), "1");
+
+        let suites = root.children("testsuite");
+        let names: Vec<_> = suites.iter().map(|suite| suite.attr("name")).collect();
+        assert_eq!(names, ["tests/a&b.rs", "tests/a&b.rs (benchmarks)"]);
+        assert_eq!(suites[0].attr("tests"), "3");
+        assert_eq!(suites[0].attr("time"), "0.004500");
+        assert_eq!(suites[0].attr("timestamp"), "2024-02-29T12:34:56");
+
+        let properties = &suites[0].children("properties")[0].children;
+        assert_eq!(properties[0].attr("value"), RunReport::SCHEMA_VERSION);
+
+        let cases = suites[0].children("testcase");
+        assert_eq!(cases[0].attr("name"), "passed <test>");
+        assert_eq!(cases[0].attr("classname"), "tests/a&b.rs");
+        assert!(cases[0].children.is_empty());
+
+        let failure = &cases[1].children("failure")[0];
+        assert_eq!(failure.attr("type"), "assertion");
+        assert_eq!(failure.attr("message"), "left: <1> & \"2\"");
+        assert_eq!(failure.text, "left: <1> & \"2\"\n[31mright: '3'[0m");
+
+        assert_eq!(cases[2].children("skipped").len(), 1);
+        assert_eq!(suites[1].children("testcase")[0].attr("name"), "bench");
+    }
+
+    #[test]
+    fn utc_timestamp_matches_known_dates() {
+        let at = |secs| utc_timestamp(UNIX_EPOCH + Duration::from_secs(secs));
+
+        assert_eq!(at(0), "1970-01-01T00:00:00");
+        assert_eq!(at(946_684_799), "1999-12-31T23:59:59");
+        assert_eq!(at(951_782_400), "2000-02-29T00:00:00");
+        assert_eq!(at(1_704_067_199), "2023-12-31T23:59:59");
+        assert_eq!(at(1_709_210_096), "2024-02-29T12:34:56");
+        // 2100 isn't a leap year.
+        assert_eq!(at(4_107_542_400), "2100-03-01T00:00:00");
+    }
+}
@@ -0,0 +1,82 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::fmt::Display;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Source of the seed used for shuffling the cases.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub(crate) enum SeedSource {
+    Cli,
+    Env,
+    #[default]
+    Random,
+}
+
+impl Display for SeedSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SeedSource::Cli => f.write_str("--seed"),
+            SeedSource::Env => f.write_str(super::config::RunnerConfig::ENV_SEED),
+            SeedSource::Random => f.write_str("random"),
+        }
+    }
+}
+
+/// Small deterministic pseudo-random generator (SplitMix64). The same seed always produces the same sequence, on every platform.
+///
+/// Tests can get one seeded from the run seed with [`TestContext::rng`](crate::cases::rust_test_case::TestContext::rng).
+#[derive(Clone, Debug)]
+pub struct SeededRng {
+    state: u64,
+}
+
+impl SeededRng {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Random number in `0..bound` range.
+    pub fn below(&mut self, bound: usize) -> usize {
+        ((self.next_u64() as u128 * bound as u128) >> 64) as usize
+    }
+
+    /// Fisher-Yates shuffle.
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            let j = self.below(i + 1);
+            items.swap(i, j);
+        }
+    }
+}
+
+/// FNV-1a hash of the name, stable across runs and platforms.
+pub(crate) fn name_hash(name: &str) -> u64 {
+    name.bytes().fold(0xCBF2_9CE4_8422_2325u64, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x0100_0000_01B3)
+    })
+}
+
+/// Seed of a single case, derived from the run seed and the case name, so it doesn't depend on the order of execution.
+pub(crate) fn case_seed(run_seed: u64, case_name: &str) -> u64 {
+    SeededRng::new(run_seed ^ name_hash(case_name)).next_u64()
+}
+
+/// Seed for runs without an explicitly provided one.
+pub(crate) fn random_seed() -> u64 {
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |time| time.as_nanos() as u64);
+    SeededRng::new(nanos ^ ((std::process::id() as u64) << 32)).next_u64()
+}
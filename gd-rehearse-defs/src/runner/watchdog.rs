@@ -0,0 +1,118 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant};
+
+use super::print::DetachedWriter;
+
+// How often the watchdog thread checks the progress.
+const POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+struct Progress {
+    /// Currently running case with its start time.
+    running: Option<(String, Instant)>,
+    last_finished: Instant,
+    last_reported: Option<Instant>,
+}
+
+/// Background thread printing the currently running case if no case finished within `stall_timeout`.
+///
+/// It only reports the stall and doesn't interrupt the case. As [`MessageWriter`](super::print::MessageWriter) can't be shared
+/// between threads, the report is printed through its [`DetachedWriter`], to the standard error and the summary file.
+pub(crate) struct Watchdog {
+    progress: Arc<Mutex<Progress>>,
+    stop: Arc<AtomicBool>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl Watchdog {
+    pub fn start(stall_timeout: Duration, writer: DetachedWriter) -> Self {
+        let progress = Arc::new(Mutex::new(Progress {
+            running: None,
+            last_finished: Instant::now(),
+            last_reported: None,
+        }));
+        let stop = Arc::new(AtomicBool::new(false));
+
+        let handle = {
+            let progress = progress.clone();
+            let stop = stop.clone();
+            std::thread::spawn(move || Self::watch(&progress, &stop, stall_timeout, &writer))
+        };
+
+        Self {
+            progress,
+            stop,
+            handle: Some(handle),
+        }
+    }
+
+    pub fn case_started(&self, name: String) {
+        if let Ok(mut progress) = self.progress.lock() {
+            progress.running = Some((name, Instant::now()));
+        }
+    }
+
+    pub fn case_finished(&self) {
+        if let Ok(mut progress) = self.progress.lock() {
+            progress.running = None;
+            progress.last_finished = Instant::now();
+            progress.last_reported = None;
+        }
+    }
+
+    fn watch(
+        progress: &Mutex<Progress>,
+        stop: &AtomicBool,
+        stall_timeout: Duration,
+        writer: &DetachedWriter,
+    ) {
+        while !stop.load(Ordering::Relaxed) {
+            std::thread::sleep(POLL_INTERVAL);
+
+            let Some(message) = Self::check_stall(progress, stall_timeout) else {
+                continue;
+            };
+            writer.println(&message);
+        }
+    }
+
+    /// Returns the report if no case finished within `stall_timeout`, releasing the lock before it's printed.
+    fn check_stall(progress: &Mutex<Progress>, stall_timeout: Duration) -> Option<String> {
+        let mut progress = progress.lock().ok()?;
+        // Report again only after another full interval.
+        let since_report = progress.last_reported.unwrap_or(progress.last_finished);
+        if since_report.elapsed() < stall_timeout {
+            return None;
+        }
+        let last_finished = progress.last_finished;
+        let message = match &progress.running {
+            Some((name, started)) => format!(
+                "\n   !! stall: no case finished in {:.1}s, still running: {name} ({:.1}s)",
+                last_finished.elapsed().as_secs_f64(),
+                started.elapsed().as_secs_f64()
+            ),
+            None => format!(
+                "\n   !! stall: no case finished in {:.1}s, none is running",
+                last_finished.elapsed().as_secs_f64()
+            ),
+        };
+        progress.last_reported = Some(Instant::now());
+        Some(message)
+    }
+}
+
+impl Drop for Watchdog {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
@@ -38,6 +38,9 @@ pub fn attribute_bench(input_decl: Declaration) -> Result<TokenStream, venial::E
     let mut skipped = false;
     let mut keyword = quote! { None };
     let mut scene_path = quote! { None };
+    let mut description = quote! { None };
+    let mut group = quote! { None };
+    let mut priority: i32 = 0;
     let mut setup_function: Option<Ident> = None;
     let mut cleanup_function: Option<Ident> = None;
 
@@ -52,6 +55,9 @@ pub fn attribute_bench(input_decl: Declaration) -> Result<TokenStream, venial::E
         AttributeIdent::ScenePath,
         AttributeIdent::Setup,
         AttributeIdent::Cleanup,
+        AttributeIdent::Description,
+        AttributeIdent::Priority,
+        AttributeIdent::Group,
     ])? {
         match ident {
             AttributeIdent::Repeat => {
@@ -93,6 +99,24 @@ pub fn attribute_bench(input_decl: Declaration) -> Result<TokenStream, venial::E
                 cleanup_function = Some(parser.get_ident()?);
                 parser.progress_puct();
             }
+            AttributeIdent::Description => {
+                parser.pop_equal_sign()?;
+                let description_lit = parser.get_literal()?;
+                description = quote! { Some( #description_lit ) };
+                parser.progress_puct();
+            }
+            AttributeIdent::Priority => {
+                parser.pop_equal_sign()?;
+                priority = parser.get_signed_integer()?;
+                parser.progress_puct();
+            }
+            AttributeIdent::Group => {
+                parser.pop_equal_sign()?;
+                let group_lit = parser.get_literal()?;
+                group = quote! { Some( #group_lit ) };
+                parser.progress_puct();
+            }
+            _ => unreachable!(),
         }
     }
 
@@ -169,7 +193,10 @@ pub fn attribute_bench(input_decl: Declaration) -> Result<TokenStream, venial::E
           repetitions: #repeats,
           scene_path: #scene_path,
           setup_function: #setup_function,
-          cleanup_function: #cleanup_function
+          cleanup_function: #cleanup_function,
+          description: #description,
+          priority: #priority,
+          group: #group
         }}
     })
 }
@@ -28,8 +28,16 @@ pub fn attribute_gditest(input_decl: Declaration) -> Result<TokenStream, Error>
 
     let mut skipped = false;
     let mut focused = false;
+    let mut smoke = false;
     let mut keyword = quote! { None };
     let mut scene_path = quote! { None };
+    let mut description = quote! { None };
+    let mut display_name = quote! { None };
+    let mut priority: i32 = 0;
+    let mut timeout = quote! { None };
+    let mut depends_on = Vec::new();
+    let mut retries = quote! { None };
+    let mut requires = quote! { None };
 
     let mut parser =
         AttributeValueParser::from_attribute_group_at_path(&func.attributes, "gditest")?;
@@ -39,6 +47,14 @@ pub fn attribute_gditest(input_decl: Declaration) -> Result<TokenStream, Error>
         AttributeIdent::Skip,
         AttributeIdent::Keyword,
         AttributeIdent::ScenePath,
+        AttributeIdent::Description,
+        AttributeIdent::Priority,
+        AttributeIdent::Timeout,
+        AttributeIdent::DependsOn,
+        AttributeIdent::Retries,
+        AttributeIdent::Requires,
+        AttributeIdent::Smoke,
+        AttributeIdent::DisplayName,
     ])? {
         match ident {
             AttributeIdent::Focus => {
@@ -49,6 +65,10 @@ pub fn attribute_gditest(input_decl: Declaration) -> Result<TokenStream, Error>
                 skipped = true;
                 parser.progress_puct();
             }
+            AttributeIdent::Smoke => {
+                smoke = true;
+                parser.progress_puct();
+            }
             AttributeIdent::Keyword => {
                 parser.pop_equal_sign()?;
                 let keyword_literal = parser.get_literal()?;
@@ -58,7 +78,62 @@ pub fn attribute_gditest(input_decl: Declaration) -> Result<TokenStream, Error>
             AttributeIdent::ScenePath => {
                 parser.pop_equal_sign()?;
                 let scene_path_lit = parser.get_literal_scene_path()?;
-                scene_path = quote! { Some( #scene_path_lit ) }
+                scene_path = quote! { Some( #scene_path_lit ) };
+                parser.progress_puct();
+            }
+            AttributeIdent::Description => {
+                parser.pop_equal_sign()?;
+                let description_lit = parser.get_literal()?;
+                description = quote! { Some( #description_lit ) };
+                parser.progress_puct();
+            }
+            AttributeIdent::DisplayName => {
+                parser.pop_equal_sign()?;
+                let display_name_lit = parser.get_literal()?;
+                display_name = quote! { Some( #display_name_lit ) };
+                parser.progress_puct();
+            }
+            AttributeIdent::Priority => {
+                parser.pop_equal_sign()?;
+                priority = parser.get_signed_integer()?;
+                parser.progress_puct();
+            }
+            AttributeIdent::Timeout => {
+                parser.pop_equal_sign()?;
+                let timeout_secs = parser.get_non_negative_number()?;
+                timeout = quote! { Some( #timeout_secs ) };
+                parser.progress_puct();
+            }
+            AttributeIdent::DependsOn => {
+                parser.pop_equal_sign()?;
+                depends_on.push(parser.get_literal()?);
+                parser.progress_puct();
+            }
+            AttributeIdent::Retries => {
+                parser.pop_equal_sign()?;
+                let retries_lit = parser.get_literal()?;
+                let retries_count = retries_lit
+                    .to_string()
+                    .parse::<u32>()
+                    .map_err(|_| venial::Error::new_at_tokens(retries_lit, "expected integer"))?;
+                retries = quote! { Some( #retries_count ) };
+                parser.progress_puct();
+            }
+            AttributeIdent::Requires => {
+                parser.pop_equal_sign()?;
+                let mode_lit = parser.get_literal()?;
+                let mode = match mode_lit.to_string().as_str() {
+                    "\"headless\"" => quote! { Headless },
+                    "\"windowed\"" => quote! { Windowed },
+                    _ => {
+                        return Err(venial::Error::new_at_tokens(
+                            mode_lit,
+                            "expected \"headless\" or \"windowed\"",
+                        ))
+                    }
+                };
+                requires = quote! { Some( ::gd_rehearse::itest::RunMode::#mode ) };
+                parser.progress_puct();
             }
             _ => unreachable!(),
         }
@@ -107,11 +182,19 @@ pub fn attribute_gditest(input_decl: Declaration) -> Result<TokenStream, Error>
             name: #test_name_str,
             skipped: #skipped,
             focused: #focused,
+            smoke: #smoke,
             keyword: #keyword,
             file: std::file!(),
             line: std::line!(),
             function: #test_name,
-            scene_path: #scene_path
+            scene_path: #scene_path,
+            description: #description,
+            display_name: #display_name,
+            priority: #priority,
+            timeout: #timeout,
+            depends_on: &[#(#depends_on),*],
+            retries: #retries,
+            requires: #requires
         });
     })
 }
@@ -31,6 +31,18 @@ mod utils;
 /// - `focus`: Forces focus run, in which only tests annotated with `focus` will be run.
 /// - `keyword`: A specific keyword that will be picked up by the runner, and the test will be run only if the runner has the same keyword specified.
 /// - `scene_path`: Godot path to the scene. If specified, given benchmark will only run if runner's scene path is the same.
+/// - `description`: Human-readable description of the test, which can be matched with `--filter-desc` command line argument.
+/// - `display_name`: Name shown in the output and reports instead of the function name. Name filters, `depends_on` and
+///   `--order-file` still match the function name.
+/// - `priority`: Integer priority of the test, `0` by default. Tests with higher priority are executed first.
+/// - `timeout`: Timeout of the test in seconds, overriding the `--timeout` command line argument. `0` disables the timeout for the test.
+/// - `depends_on`: Name of the test which needs to pass before this one is run, either bare `"test_name"` or `"file.rs::test_name"`.
+///   Can be repeated. Dependencies are executed first, and if any of them fails or isn't run, the test is skipped.
+/// - `retries`: How many times the test is retried after failing, overriding the `--retries` command line argument.
+/// - `smoke`: Marks the test as part of the quick smoke subset. During run with `--smoke` command line argument, only the smoke
+///   tests are executed.
+/// - `requires`: Mode of the run the test requires, either `"headless"` or `"windowed"`. In other mode the
+///   test is skipped, or failed with `--fail-on-mode-skip` command line argument.
 ///
 /// ## Examples
 /// ```no_run
@@ -93,6 +105,10 @@ pub fn gditest(meta: TokenStream, input: TokenStream) -> TokenStream {
 /// - `setup`: Optional function that will be executed before benchmark execution, to set up the scene for benchmarks.
 /// - `cleanup`: Optional function that will be executed after benchmark execution, to clean up after benchmarks. Rarely needed, as when
 ///   `setup` is present, the default cleanup function should always clean up efficiently.
+/// - `description`: Human-readable description of the benchmark, which can be matched with `--filter-desc` command line argument.
+/// - `priority`: Integer priority of the benchmark, `0` by default. Benchmarks with higher priority are executed first.
+/// - `group`: Name of the group of benchmarks comparing alternative implementations of the same operation. With
+///   `--bench-group-relative` command line argument, their medians are additionally shown relative to the fastest one in the group.
 ///
 /// ## Examples
 /// ```no_run
@@ -21,6 +21,15 @@ pub enum AttributeIdent {
     ScenePath,
     Setup,
     Cleanup,
+    Description,
+    Priority,
+    Timeout,
+    DependsOn,
+    Retries,
+    Group,
+    Requires,
+    Smoke,
+    DisplayName,
 }
 
 impl AttributeIdent {
@@ -33,6 +42,15 @@ impl AttributeIdent {
             "scene_path" => Some(Self::ScenePath),
             "setup" => Some(Self::Setup),
             "cleanup" => Some(Self::Cleanup),
+            "description" => Some(Self::Description),
+            "priority" => Some(Self::Priority),
+            "timeout" => Some(Self::Timeout),
+            "depends_on" => Some(Self::DependsOn),
+            "retries" => Some(Self::Retries),
+            "group" => Some(Self::Group),
+            "requires" => Some(Self::Requires),
+            "smoke" => Some(Self::Smoke),
+            "display_name" => Some(Self::DisplayName),
             _ => None,
         }
     }
@@ -46,6 +64,15 @@ impl AttributeIdent {
             AttributeIdent::ScenePath => "scene_path".to_owned(),
             AttributeIdent::Setup => "setup".to_owned(),
             AttributeIdent::Cleanup => "cleanup".to_owned(),
+            AttributeIdent::Description => "description".to_owned(),
+            AttributeIdent::Priority => "priority".to_owned(),
+            AttributeIdent::Timeout => "timeout".to_owned(),
+            AttributeIdent::DependsOn => "depends_on".to_owned(),
+            AttributeIdent::Retries => "retries".to_owned(),
+            AttributeIdent::Group => "group".to_owned(),
+            AttributeIdent::Requires => "requires".to_owned(),
+            AttributeIdent::Smoke => "smoke".to_owned(),
+            AttributeIdent::DisplayName => "display_name".to_owned(),
         }
     }
 
@@ -114,6 +141,33 @@ impl AttributeValueParser {
         Err(venial::Error::new("expected literal"))
     }
 
+    /// Gets integer literal, optionally preceded by minus sign.
+    pub fn get_signed_integer(&mut self) -> Result<i32, venial::Error> {
+        let is_negative =
+            matches!(self.tokens.front(), Some(TokenTree::Punct(punct)) if punct.as_char() == '-');
+        if is_negative {
+            _ = self.tokens.pop_front();
+        }
+        let literal = self.get_literal()?;
+        let value = literal
+            .to_string()
+            .parse::<i32>()
+            .map_err(|_| venial::Error::new_at_tokens(literal, "expected integer"))?;
+        Ok(if is_negative { -value } else { value })
+    }
+
+    /// Gets non-negative number literal, either integer or float.
+    pub fn get_non_negative_number(&mut self) -> Result<f64, venial::Error> {
+        let literal = self.get_literal()?;
+        match literal.to_string().parse::<f64>() {
+            Ok(value) if value >= 0.0 => Ok(value),
+            _ => Err(venial::Error::new_at_tokens(
+                literal,
+                "expected non-negative number",
+            )),
+        }
+    }
+
     pub fn get_ident(&mut self) -> Result<Ident, venial::Error> {
         if let Some(token) = self.tokens.pop_front() {
             if let TokenTree::Ident(ident) = token {
@@ -15,9 +15,18 @@ pub use gd_rehearse_defs::runner::GdTestRunner;
 
 /// Contains all symbols necessary to use [`#[gditest]`](macro@gd_rehearse_macros::gditest) macro.
 pub mod itest {
-    pub use gd_rehearse_defs::cases::rust_test_case::{RustTestCase, TestContext};
+    pub use gd_rehearse_defs::assertions::soft::{soft_assertions, SoftAssertions};
+    pub use gd_rehearse_defs::cases::rust_test_case::{
+        require_service, skip_test, RunMode, RustTestCase, TestContext,
+    };
     pub use gd_rehearse_defs::cases::CaseContext;
     pub use gd_rehearse_defs::registry::itest::*;
+    pub use gd_rehearse_defs::runner::shuffle::SeededRng;
+    pub use gd_rehearse_defs::{
+        assert_array_contains, assert_array_eq_unordered, assert_dict_contains, assert_eventually,
+        assert_panics, assert_panics_with, assert_resource_matches, assert_variant_is_nil,
+        assert_variant_type, ignore_suite, suite_timeout,
+    };
     pub use gd_rehearse_macros::gditest;
 }
 
@@ -28,3 +37,18 @@ pub mod bench {
     pub use gd_rehearse_defs::registry::bench::*;
     pub use gd_rehearse_macros::gdbench;
 }
+
+/// Contains symbols necessary to build and validate the [`GdTestRunner`] configuration outside of the runner.
+pub mod config {
+    pub use gd_rehearse_defs::runner::config::{
+        ConfigError, ConfigErrorKind, RunnerConfig, RunnerConfigBuilder,
+    };
+}
+
+/// Contains symbols necessary to implement custom reporters, registered with [`GdTestRunner::add_reporter`].
+pub mod report {
+    pub use gd_rehearse_defs::cases::FailureKind;
+    pub use gd_rehearse_defs::runner::report::{
+        CaseReport, ReportCaseKind, ReportOutcome, Reporter, RunCounts, RunReport,
+    };
+}
@@ -63,3 +63,18 @@ fn with_setup_and_cleanup(ctx: &BenchContext) -> bool {
     let _setup = ctx.get_setup_node("SetupTest");
     true
 }
+
+#[gdbench(description = "benchmark with human-readable description")]
+fn described_bench() -> i32 {
+    423
+}
+
+#[gdbench(group = "sum")]
+fn sum_iter() -> i32 {
+    (1..=100).sum()
+}
+
+#[gdbench(group = "sum")]
+fn sum_formula() -> i32 {
+    100 * 101 / 2
+}
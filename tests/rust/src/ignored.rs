@@ -0,0 +1,14 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+*/
+
+use gd_rehearse::itest::*;
+
+ignore_suite!("checks that ignored suites aren't run");
+
+#[gditest]
+fn test_in_ignored_suite() {
+    panic!("tests in ignored suites shouldn't run without --run-ignored");
+}
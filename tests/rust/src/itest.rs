@@ -4,16 +4,32 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
 */
 
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+use std::sync::Arc;
+
 use gd_rehearse::itest::*;
-use godot::engine::Object;
-use godot::obj::Gd;
+use godot::builtin::meta::ToGodot;
+use godot::builtin::{
+    dict, varray, Color, PackedColorArray, PackedFloat32Array, StringName, VariantType,
+};
+use godot::engine::{Gradient, Object, Resource};
+use godot::obj::{Gd, NewGd};
 
-#[gditest]
+// Generous, as the whole suite normally finishes in well under a second.
+suite_timeout!(60.0);
+
+#[gditest(smoke)]
 fn simple_test() {
     let test = 1 + 1;
     assert_eq!(test, 2);
 }
 
+#[gditest(display_name = "simple test shown under display name")]
+fn display_named_test() {
+    let test = 2 * 2;
+    assert_eq!(test, 4);
+}
+
 #[gditest]
 fn second_test() {
     let test = 1 + 1;
@@ -68,3 +84,202 @@ fn access_ctx_with_path(ctx: &TestContext) {
     let val_as_int = value.to::<i32>();
     assert_eq!(val_as_int, 344);
 }
+
+#[gditest(description = "test with human-readable description")]
+fn described_test() {}
+
+static PRIORITIZED_RAN: AtomicBool = AtomicBool::new(false);
+
+// Defined before `prioritized_test` in the file, but executed after it.
+#[gditest(priority = -10)]
+fn deprioritized_test() {
+    assert!(
+        PRIORITIZED_RAN.load(Ordering::SeqCst),
+        "test with higher priority should run first"
+    );
+}
+
+#[gditest(priority = 10)]
+fn prioritized_test() {
+    PRIORITIZED_RAN.store(true, Ordering::SeqCst);
+}
+
+#[gditest]
+fn soft_assertions_test() {
+    let values = [1, 2, 3];
+    let sum = soft_assertions(|ctx| {
+        ctx.check_eq(values.len(), 3);
+        ctx.check(values.contains(&2), "values should contain 2");
+        ctx.check_ne(values[0], 0);
+        values.iter().sum::<i32>()
+    });
+    assert_eq!(sum, 6);
+}
+
+#[gditest]
+fn soft_assertions_collect_failures_test() {
+    let failing = || {
+        soft_assertions(|ctx| {
+            ctx.check(false, "first failure");
+            ctx.check(true, "not a failure");
+            ctx.check_eq(1, 2);
+        })
+    };
+    assert_panics_with!(failing, "2 soft assertions failed");
+    assert_panics_with!(failing, "first failure");
+    assert_panics_with!(failing, "left: 1, right: 2");
+}
+
+#[gditest]
+fn collection_assertions_test() {
+    let dict = dict! { "name": "player", "stats": dict! { "hp": 100 } };
+    assert_dict_contains!(dict, dict! { "stats": dict! { "hp": 100 } });
+
+    let values = varray![3, "two", varray![1]];
+    assert_array_eq_unordered!(values, varray![varray![1], 3, "two"]);
+    assert_array_contains!(values, varray!["two"], "values should contain a string");
+}
+
+#[gditest]
+fn variant_assertions_test() {
+    let dict = dict! { "name": "player" };
+    assert_variant_type!(dict.get_or_nil("name"), VariantType::STRING);
+    assert_variant_is_nil!(dict.get_or_nil("hp"), "missing key should be nil");
+}
+
+#[gditest]
+fn panic_assertions_test() {
+    let values: Vec<i32> = Vec::new();
+    assert_panics!(|| values[0]);
+    assert_panics_with!(|| values.first().expect("no values"), "no values");
+}
+
+const GOLDEN_GRADIENT: &str = "res://golden/gradient.tres";
+
+/// Gradient matching the one stored in [`GOLDEN_GRADIENT`].
+fn golden_gradient() -> Gd<Gradient> {
+    let mut gradient = Gradient::new_gd();
+    gradient.set_offsets(PackedFloat32Array::from(&[0.0, 0.5, 1.0]));
+    gradient.set_colors(PackedColorArray::from(&[
+        Color::BLACK,
+        Color::RED,
+        Color::WHITE,
+    ]));
+    gradient
+}
+
+#[gditest]
+fn resource_assertion_test() {
+    assert_resource_matches!(golden_gradient().upcast::<Resource>(), GOLDEN_GRADIENT);
+}
+
+#[gditest]
+fn resource_assertion_mismatch_test() {
+    let mut recolored = golden_gradient();
+    recolored.set_color(1, Color::BLUE);
+    assert_panics_with!(
+        || assert_resource_matches!(recolored.upcast::<Resource>(), GOLDEN_GRADIENT),
+        "property 'colors' differs"
+    );
+
+    // Properties only the actual resource has fail the assertion as well.
+    let mut with_meta = golden_gradient();
+    with_meta.set_meta(StringName::from("generated_by"), "test".to_variant());
+    assert_panics_with!(
+        || assert_resource_matches!(with_meta.upcast::<Resource>(), GOLDEN_GRADIENT),
+        "unexpected property 'metadata/generated_by'"
+    );
+}
+
+#[gditest]
+fn eventually_assertion_test() {
+    let done = Arc::new(AtomicBool::new(false));
+    let flag = done.clone();
+    std::thread::spawn(move || flag.store(true, Ordering::SeqCst));
+    assert_eventually!(done.load(Ordering::SeqCst), 1.0);
+}
+
+#[gditest(timeout = 0)]
+fn no_timeout_test() {}
+
+#[gditest(timeout = 2.5)]
+fn timeout_test() {}
+
+static PREREQUISITES_PASSED: AtomicU32 = AtomicU32::new(0);
+
+// Defined before its prerequisites in the file, but executed after them.
+#[gditest(
+    depends_on = "first_prerequisite_test",
+    depends_on = "itest.rs::second_prerequisite_test"
+)]
+fn dependent_test() {
+    assert_eq!(
+        PREREQUISITES_PASSED.load(Ordering::SeqCst),
+        2,
+        "prerequisites should run first"
+    );
+}
+
+#[gditest]
+fn first_prerequisite_test() {
+    PREREQUISITES_PASSED.fetch_add(1, Ordering::SeqCst);
+}
+
+#[gditest]
+fn second_prerequisite_test() {
+    PREREQUISITES_PASSED.fetch_add(1, Ordering::SeqCst);
+}
+
+static RETRIED_ATTEMPTS: AtomicU32 = AtomicU32::new(0);
+
+// Passes only on the third attempt, so the run fails without per-test retries.
+#[gditest(retries = 3)]
+fn retried_test() {
+    let attempt = RETRIED_ATTEMPTS.fetch_add(1, Ordering::SeqCst) + 1;
+    assert!(attempt >= 3, "flaky failure on attempt {attempt}");
+}
+
+#[gditest]
+fn seeded_data_test(ctx: &TestContext) {
+    let mut rng = ctx.rng();
+    let first = rng.next_u64();
+    assert_eq!(ctx.rng().next_u64(), first);
+}
+
+#[gditest(requires = "headless")]
+fn headless_only_test() {
+    assert!(godot::engine::DisplayServer::singleton().get_name() == "headless".into());
+}
+
+#[gditest(timeout = 5)]
+fn checkpointed_test(ctx: &TestContext) {
+    ctx.checkpoint("scene tree accessed");
+    ctx.scene_tree().instance_id();
+    ctx.checkpoint("done");
+}
+
+#[gditest]
+fn runtime_skipped_test() {
+    if std::env::var("GD_REHEARSE_NEVER_SET").is_err() {
+        skip_test("environment variable not set");
+    }
+    unreachable!("test should have been skipped");
+}
+
+#[gditest]
+fn required_service_test() {
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let address = listener.local_addr().unwrap();
+    require_service(&address.to_string());
+}
+
+#[gditest]
+fn unreachable_service_test() {
+    // Nothing listens on the port once its listener is closed.
+    let address = std::net::TcpListener::bind("127.0.0.1:0")
+        .unwrap()
+        .local_addr()
+        .unwrap();
+    require_service(&address.to_string());
+    unreachable!("test should have been skipped");
+}
@@ -7,6 +7,7 @@
 use godot::init::{gdextension, ExtensionLibrary};
 
 mod bench;
+mod ignored;
 mod itest;
 
 struct GodotTestTests;